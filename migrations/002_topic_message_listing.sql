@@ -0,0 +1,7 @@
+-- Support cursor pagination of requests within a topic (UUIDv7 ids are time-ordered)
+CREATE INDEX IF NOT EXISTS idx_email_requests_topic_id_id
+ON email_requests(topic_id, id);
+
+-- Support case-insensitive recipient lookups
+CREATE INDEX IF NOT EXISTS idx_email_requests_to_email_lower
+ON email_requests(lower(to_email));
@@ -0,0 +1,7 @@
+-- Store Idempotency-Key values for POST /v1/messages so retried calls replay the original response
+CREATE TABLE idempotency_keys (
+    key VARCHAR(255) PRIMARY KEY,
+    request_hash CHAR(64) NOT NULL,
+    response JSONB,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
@@ -0,0 +1,3 @@
+-- Track how many times a request was recovered from a stuck Processing state
+ALTER TABLE email_requests
+ADD COLUMN recovery_count SMALLINT NOT NULL DEFAULT 0;
@@ -0,0 +1,5 @@
+-- Track publish attempts so transient failures can be retried with backoff
+ALTER TABLE email_requests
+ADD COLUMN attempts SMALLINT NOT NULL DEFAULT 0,
+ADD COLUMN next_attempt_at TIMESTAMPTZ,
+ADD COLUMN attempt_history JSONB NOT NULL DEFAULT '[]'::jsonb;
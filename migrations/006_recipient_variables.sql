@@ -0,0 +1,3 @@
+-- Per-recipient personalization variables rendered into subject and content at publish time
+ALTER TABLE email_requests
+ADD COLUMN vars JSONB;
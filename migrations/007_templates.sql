@@ -0,0 +1,36 @@
+-- Create email_templates table
+CREATE TABLE email_templates (
+    id SERIAL PRIMARY KEY,
+    name VARCHAR(100) NOT NULL,
+    description VARCHAR(255),
+    latest_version INTEGER NOT NULL DEFAULT 1,
+    deleted_at TIMESTAMPTZ,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+-- Template names are unique among non-deleted templates
+CREATE UNIQUE INDEX IF NOT EXISTS idx_email_templates_name_active
+ON email_templates(name)
+WHERE deleted_at IS NULL;
+
+-- Create email_template_versions table (immutable once created)
+CREATE TABLE email_template_versions (
+    id SERIAL PRIMARY KEY,
+    template_id INTEGER NOT NULL REFERENCES email_templates(id),
+    version INTEGER NOT NULL,
+    subject VARCHAR(255) NOT NULL,
+    content TEXT NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE (template_id, version)
+);
+
+-- Contents either carry subject/content directly or reference a template version
+ALTER TABLE email_contents
+ADD COLUMN template_version_id INTEGER REFERENCES email_template_versions(id),
+ALTER COLUMN subject DROP NOT NULL,
+ALTER COLUMN content DROP NOT NULL;
+
+ALTER TABLE email_contents
+ADD CONSTRAINT email_contents_source_check
+CHECK (template_version_id IS NOT NULL OR (subject IS NOT NULL AND content IS NOT NULL));
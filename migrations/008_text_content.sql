@@ -0,0 +1,3 @@
+-- Optional plain-text alternative; derived from the HTML content when NULL
+ALTER TABLE email_contents
+ADD COLUMN text_content TEXT;
@@ -0,0 +1,12 @@
+-- Distinguish opens triggered by image proxies / privacy prefetchers from human opens
+ALTER TABLE email_results
+ADD COLUMN machine_open BOOLEAN;
+
+UPDATE email_results SET machine_open = FALSE WHERE status = 'Open';
+
+-- Record at most one human and one machine open per request
+DROP INDEX IF EXISTS idx_email_results_request_status_unique;
+
+CREATE UNIQUE INDEX IF NOT EXISTS idx_email_results_open_unique
+ON email_results(request_id, machine_open)
+WHERE status = 'Open';
@@ -0,0 +1,10 @@
+-- Track SNS subscriptions delivering SES events to /v1/events/results
+CREATE TABLE sns_subscriptions (
+    topic_arn VARCHAR(255) PRIMARY KEY,
+    subscription_arn VARCHAR(255),
+    status VARCHAR(50) NOT NULL,
+    confirmed_at TIMESTAMPTZ,
+    unsubscribed_at TIMESTAMPTZ,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
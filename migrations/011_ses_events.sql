@@ -0,0 +1,34 @@
+-- Store SES events as parsed JSON objects with queryable key columns
+ALTER TABLE email_results
+    ADD COLUMN ses_message_id VARCHAR(255),
+    ADD COLUMN event_at TIMESTAMPTZ,
+    ADD COLUMN bounce_type VARCHAR(50),
+    ADD COLUMN bounce_sub_type VARCHAR(50),
+    ADD COLUMN complaint_feedback_type VARCHAR(50),
+    ADD COLUMN diagnostic TEXT;
+
+-- SES events were previously stored as a JSON string holding the SNS message body
+UPDATE email_results
+SET raw = (raw #>> '{}')::jsonb
+WHERE jsonb_typeof(raw) = 'string';
+
+UPDATE email_results
+SET ses_message_id = raw->'mail'->>'messageId',
+    event_at = COALESCE(
+        raw->'bounce'->>'timestamp',
+        raw->'complaint'->>'timestamp',
+        raw->'delivery'->>'timestamp',
+        raw->'mail'->>'timestamp'
+    )::timestamptz,
+    bounce_type = raw->'bounce'->>'bounceType',
+    bounce_sub_type = raw->'bounce'->>'bounceSubType',
+    complaint_feedback_type = raw->'complaint'->>'complaintFeedbackType',
+    diagnostic = COALESCE(
+        raw->'bounce'->'bouncedRecipients'->0->>'diagnosticCode',
+        raw->'delivery'->>'smtpResponse'
+    )
+WHERE raw ? 'mail';
+
+CREATE INDEX IF NOT EXISTS idx_email_results_ses_message_id
+ON email_results(ses_message_id)
+WHERE ses_message_id IS NOT NULL;
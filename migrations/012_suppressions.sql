@@ -0,0 +1,14 @@
+-- Recipients that must not receive further email (global when topic_id is NULL)
+CREATE TABLE suppressions (
+    id SERIAL PRIMARY KEY,
+    email VARCHAR(255) NOT NULL,
+    topic_id VARCHAR(255),
+    reason VARCHAR(50) NOT NULL,
+    request_id UUID REFERENCES email_requests(id) ON DELETE SET NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+-- Emails are stored lowercased; one entry per address and scope
+CREATE UNIQUE INDEX IF NOT EXISTS idx_suppressions_email_topic
+ON suppressions(email, COALESCE(topic_id, ''));
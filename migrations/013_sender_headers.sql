@@ -0,0 +1,8 @@
+-- Per-message sender identity, extra recipients and custom headers
+ALTER TABLE email_contents
+    ADD COLUMN from_email VARCHAR(255),
+    ADD COLUMN from_name VARCHAR(100),
+    ADD COLUMN reply_to TEXT[] NOT NULL DEFAULT '{}',
+    ADD COLUMN cc TEXT[] NOT NULL DEFAULT '{}',
+    ADD COLUMN bcc TEXT[] NOT NULL DEFAULT '{}',
+    ADD COLUMN headers JSONB NOT NULL DEFAULT '{}';
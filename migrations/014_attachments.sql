@@ -0,0 +1,14 @@
+-- Attachments are stored once per content and shared by all of its requests
+CREATE TABLE email_attachments (
+    id SERIAL PRIMARY KEY,
+    content_id INTEGER NOT NULL REFERENCES email_contents(id) ON DELETE CASCADE,
+    filename VARCHAR(255) NOT NULL,
+    content_type VARCHAR(255) NOT NULL,
+    cid VARCHAR(255),
+    size INTEGER NOT NULL,
+    data BYTEA NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_email_attachments_content_id
+ON email_attachments(content_id);
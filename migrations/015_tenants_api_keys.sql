@@ -0,0 +1,60 @@
+-- Tenants own topics, requests, templates and suppressions
+CREATE TABLE tenants (
+    id SERIAL PRIMARY KEY,
+    name VARCHAR(100) NOT NULL UNIQUE,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+-- Existing data and the bootstrap API_KEY belong to the default tenant
+INSERT INTO tenants (id, name) VALUES (1, 'default');
+SELECT setval(pg_get_serial_sequence('tenants', 'id'), 1);
+
+-- API keys are stored as SHA-256 hashes; the plaintext is only returned on creation
+CREATE TABLE api_keys (
+    id SERIAL PRIMARY KEY,
+    tenant_id INTEGER NOT NULL REFERENCES tenants(id),
+    name VARCHAR(100) NOT NULL,
+    key_prefix VARCHAR(16) NOT NULL,
+    key_hash CHAR(64) NOT NULL UNIQUE,
+    last_used_at TIMESTAMPTZ,
+    revoked_at TIMESTAMPTZ,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_api_keys_tenant_id
+ON api_keys(tenant_id);
+
+ALTER TABLE email_requests
+ADD COLUMN tenant_id INTEGER NOT NULL DEFAULT 1 REFERENCES tenants(id);
+
+DROP INDEX IF EXISTS idx_email_requests_topic_id_id;
+CREATE INDEX IF NOT EXISTS idx_email_requests_tenant_topic_id
+ON email_requests(tenant_id, topic_id, id);
+
+ALTER TABLE email_templates
+ADD COLUMN tenant_id INTEGER NOT NULL DEFAULT 1 REFERENCES tenants(id);
+
+DROP INDEX IF EXISTS idx_email_templates_name_active;
+CREATE UNIQUE INDEX IF NOT EXISTS idx_email_templates_tenant_name_active
+ON email_templates(tenant_id, name)
+WHERE deleted_at IS NULL;
+
+ALTER TABLE suppressions
+ADD COLUMN tenant_id INTEGER NOT NULL DEFAULT 1 REFERENCES tenants(id);
+
+DROP INDEX IF EXISTS idx_suppressions_email_topic;
+CREATE UNIQUE INDEX IF NOT EXISTS idx_suppressions_tenant_email_topic
+ON suppressions(tenant_id, email, COALESCE(topic_id, ''));
+
+-- Idempotency keys only need to be unique per tenant
+ALTER TABLE idempotency_keys
+ADD COLUMN tenant_id INTEGER NOT NULL DEFAULT 1 REFERENCES tenants(id);
+
+ALTER TABLE idempotency_keys DROP CONSTRAINT idempotency_keys_pkey;
+ALTER TABLE idempotency_keys ADD PRIMARY KEY (tenant_id, key);
+
+-- New rows must always name their tenant
+ALTER TABLE email_requests ALTER COLUMN tenant_id DROP DEFAULT;
+ALTER TABLE email_templates ALTER COLUMN tenant_id DROP DEFAULT;
+ALTER TABLE suppressions ALTER COLUMN tenant_id DROP DEFAULT;
+ALTER TABLE idempotency_keys ALTER COLUMN tenant_id DROP DEFAULT;
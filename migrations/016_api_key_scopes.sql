@@ -0,0 +1,15 @@
+-- Permissions, topic restrictions and expiry for API keys
+ALTER TABLE api_keys
+ADD COLUMN scopes TEXT[] NOT NULL DEFAULT '{}',
+ADD COLUMN topic_prefix VARCHAR(50),
+ADD COLUMN expires_at TIMESTAMPTZ;
+
+-- Keys issued before scopes existed keep full access to their tenant's data
+UPDATE api_keys
+SET scopes = ARRAY[
+    'messages:send', 'messages:read', 'stats:read',
+    'templates:read', 'templates:write',
+    'suppressions:read', 'suppressions:write'
+];
+
+ALTER TABLE api_keys ALTER COLUMN scopes DROP DEFAULT;
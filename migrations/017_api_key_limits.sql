@@ -0,0 +1,13 @@
+-- Per-key limits (NULL falls back to the configured defaults, 0 means unlimited)
+ALTER TABLE api_keys
+ADD COLUMN requests_per_sec INTEGER,
+ADD COLUMN daily_recipient_quota BIGINT,
+ADD COLUMN monthly_recipient_quota BIGINT;
+
+-- Recipients accepted per key and UTC day; monthly usage is the sum over the month
+CREATE TABLE api_key_usage (
+    api_key_id INTEGER NOT NULL REFERENCES api_keys(id) ON DELETE CASCADE,
+    day DATE NOT NULL,
+    recipients BIGINT NOT NULL DEFAULT 0,
+    PRIMARY KEY (api_key_id, day)
+);
@@ -0,0 +1,8 @@
+-- Message priority lane (0 = normal/bulk, 1 = high/transactional)
+ALTER TABLE email_requests
+ADD COLUMN priority SMALLINT NOT NULL DEFAULT 0;
+
+-- The scheduler claims each priority lane separately
+CREATE INDEX IF NOT EXISTS idx_email_requests_priority_scheduled
+ON email_requests(priority, scheduled_at NULLS FIRST, created_at)
+WHERE status = 0;
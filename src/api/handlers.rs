@@ -1,22 +1,45 @@
 use crate::{
+    api::middleware::AuthContext,
     config::AppConfig,
     dto::*,
     error::{AppError, Result},
-    models::email::EmailStatus,
+    models::{
+        attachment::ATTACHMENT_TOKEN_PURPOSE,
+        email::{
+            click_token_subject, EmailRequest, EmailRequestWithContent, EmailResult, EmailStatus,
+            MessagePriority, CLICK_TOKEN_PURPOSE, OPEN_TOKEN_PURPOSE, UNSUBSCRIBE_TOKEN_PURPOSE,
+        },
+        ses::{SesEvent, SesEventType},
+        suppression::{
+            normalize_email, Suppression, SUPPRESSION_REASON_BOUNCE, SUPPRESSION_REASON_COMPLAINT,
+            SUPPRESSION_REASON_MANUAL, SUPPRESSION_REASON_UNSUBSCRIBE,
+        },
+        template::{EmailTemplate, EmailTemplateVersion},
+        tenant::{api_key_prefix, generate_api_key, hash_api_key, ApiKey, ApiScope, Tenant},
+        usage::{month_start, next_day_start, next_month_start, secs_until, start_of_day},
+    },
+    services::{
+        rate_limit::KeyedRateLimiter,
+        sns::{SnsVerificationError, SnsVerifier},
+        token::TokenSigner,
+        tracking,
+    },
 };
 use axum::body::Bytes;
 use axum::{
-    extract::{Path, Query, State},
-    http::StatusCode,
+    async_trait,
+    extract::{ConnectInfo, FromRequest, Multipart, Path, Query, Request, State},
+    http::{header, HeaderMap, StatusCode},
     response::{IntoResponse, Response},
-    Json,
+    Extension, Json,
 };
-use chrono::Utc;
+use chrono::{DateTime, Utc};
 use serde::Deserialize;
 use serde_json::Value;
+use sha2::{Digest, Sha256};
 use sqlx::PgPool;
-use std::{collections::HashMap, sync::Arc, time::Instant};
-use tracing::{debug, info, warn};
+use std::{collections::HashMap, net::SocketAddr, sync::Arc, time::Instant};
+use tracing::{debug, error, info, warn};
 use uuid::Uuid;
 use validator::Validate;
 
@@ -24,11 +47,240 @@ use validator::Validate;
 pub struct AppState {
     pub db: PgPool,
     pub config: Arc<AppConfig>,
+    pub signer: Arc<TokenSigner>,
+    pub sns: Arc<SnsVerifier>,
+    /// API 키별 초당 요청 수 제한
+    pub rate_limiter: Arc<KeyedRateLimiter<i32>>,
+}
+
+/// 재시도 시 중복 발송을 막기 위한 멱등성 키 헤더
+const IDEMPOTENCY_KEY_HEADER: &str = "idempotency-key";
+
+fn idempotency_key(headers: &HeaderMap) -> Result<Option<String>> {
+    let Some(value) = headers.get(IDEMPOTENCY_KEY_HEADER) else {
+        return Ok(None);
+    };
+
+    let key = value
+        .to_str()
+        .map_err(|_| AppError::Validation("Idempotency-Key must be visible ASCII".to_string()))?
+        .trim();
+
+    if key.is_empty() || key.len() > 255 {
+        return Err(AppError::Validation(
+            "Idempotency-Key must be between 1 and 255 characters".to_string(),
+        ));
+    }
+
+    Ok(Some(key.to_string()))
+}
+
+/// 요청 본문의 SHA-256 해시 (키 순서가 정렬된 JSON 기준)
+fn hash_request(payload: &CreateMessageRequest) -> Result<String> {
+    let canonical = serde_json::to_vec(&serde_json::to_value(payload)?)?;
+    Ok(format!("{:x}", Sha256::digest(&canonical)))
+}
+
+/// 메시지의 제목/본문 출처 (직접 입력 또는 템플릿 버전)
+struct MessageSource {
+    subject: String,
+    content: String,
+    template_version_id: Option<i32>,
+}
+
+async fn resolve_message_source(
+    db: &PgPool,
+    tenant_id: i32,
+    message: &MessageRequest,
+) -> Result<MessageSource> {
+    let Some(template_id) = message.template_id else {
+        return Ok(MessageSource {
+            subject: message.subject.as_deref().unwrap_or("").trim().to_string(),
+            content: message.content.as_deref().unwrap_or("").trim().to_string(),
+            template_version_id: None,
+        });
+    };
+
+    let version =
+        find_template_version(db, tenant_id, template_id, message.template_version).await?;
+
+    Ok(MessageSource {
+        subject: version.subject,
+        content: version.content,
+        template_version_id: Some(version.id),
+    })
+}
+
+/// 키의 수신자 사용량을 늘리고 일간/월간 할당량을 넘으면 429 (트랜잭션이 롤백되면 사용량도 취소)
+async fn reserve_recipient_quota(
+    tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+    auth: &AuthContext,
+    recipients: i64,
+) -> Result<()> {
+    let Some(api_key_id) = auth.api_key_id else {
+        return Ok(());
+    };
+    let now = Utc::now();
+    let today = now.date_naive();
+
+    // 같은 키의 동시 요청은 오늘 사용량 행의 잠금으로 직렬화
+    let daily_used = sqlx::query_scalar!(
+        "INSERT INTO api_key_usage (api_key_id, day, recipients)
+         VALUES ($1, $2, $3)
+         ON CONFLICT (api_key_id, day)
+         DO UPDATE SET recipients = api_key_usage.recipients + EXCLUDED.recipients
+         RETURNING recipients",
+        api_key_id,
+        today,
+        recipients
+    )
+    .fetch_one(&mut **tx)
+    .await?;
+
+    if let Some(quota) = auth.limits.daily_recipients {
+        if daily_used > quota {
+            return Err(AppError::TooManyRequests {
+                message: format!(
+                    "Daily recipient quota exceeded: {} of {} used",
+                    daily_used - recipients,
+                    quota
+                ),
+                retry_after_secs: secs_until(now, start_of_day(next_day_start(today))),
+            });
+        }
+    }
+
+    if let Some(quota) = auth.limits.monthly_recipients {
+        let monthly_used = sqlx::query_scalar!(
+            r#"SELECT COALESCE(SUM(recipients), 0)::bigint as "used!"
+               FROM api_key_usage
+               WHERE api_key_id = $1 AND day >= $2"#,
+            api_key_id,
+            month_start(today)
+        )
+        .fetch_one(&mut **tx)
+        .await?;
+
+        if monthly_used > quota {
+            return Err(AppError::TooManyRequests {
+                message: format!(
+                    "Monthly recipient quota exceeded: {} of {} used",
+                    monthly_used - recipients,
+                    quota
+                ),
+                retry_after_secs: secs_until(now, start_of_day(next_month_start(today))),
+            });
+        }
+    }
+
+    Ok(())
+}
+
+/// JSON 본문 또는 `multipart/form-data`로 받은 메시지 생성 요청
+///
+/// multipart 요청은 `payload` 필드에 JSON 요청을 담고, 파일은 `attachments`(첫 번째 메시지)
+/// 또는 `attachments[N]`(N번째 메시지) 필드로 업로드합니다. 인라인 이미지는 파일 필드의
+/// `Content-ID` 헤더로 지정합니다.
+pub struct MessagePayload(pub CreateMessageRequest);
+
+#[async_trait]
+impl<S> FromRequest<S> for MessagePayload
+where
+    S: Send + Sync,
+{
+    type Rejection = Response;
+
+    async fn from_request(req: Request, state: &S) -> std::result::Result<Self, Self::Rejection> {
+        let is_multipart = req
+            .headers()
+            .get(header::CONTENT_TYPE)
+            .and_then(|value| value.to_str().ok())
+            .is_some_and(|value| value.starts_with("multipart/form-data"));
+
+        if !is_multipart {
+            let Json(payload) = Json::<CreateMessageRequest>::from_request(req, state)
+                .await
+                .map_err(IntoResponse::into_response)?;
+            return Ok(Self(payload));
+        }
+
+        let multipart = Multipart::from_request(req, state)
+            .await
+            .map_err(IntoResponse::into_response)?;
+        parse_multipart_message(multipart).await.map(Self)
+    }
+}
+
+async fn parse_multipart_message(
+    mut multipart: Multipart,
+) -> std::result::Result<CreateMessageRequest, Response> {
+    let invalid = |message: String| AppError::Validation(message).into_response();
+
+    let mut payload: Option<CreateMessageRequest> = None;
+    let mut uploads = Vec::new();
+
+    while let Some(field) = multipart
+        .next_field()
+        .await
+        .map_err(IntoResponse::into_response)?
+    {
+        let name = field.name().unwrap_or("").to_string();
+
+        if name == MULTIPART_PAYLOAD_FIELD {
+            let bytes = field.bytes().await.map_err(IntoResponse::into_response)?;
+            payload = Some(
+                serde_json::from_slice(&bytes)
+                    .map_err(|e| invalid(format!("Invalid payload field: {}", e)))?,
+            );
+            continue;
+        }
+
+        let index = multipart_attachment_index(&name)
+            .ok_or_else(|| invalid(format!("Unexpected multipart field: {}", name)))?;
+        let filename = field
+            .file_name()
+            .map(str::to_string)
+            .ok_or_else(|| invalid(format!("Attachment field {} must have a filename", name)))?;
+        let content_type = field.content_type().map(str::to_string);
+        let content_id = field
+            .headers()
+            .get("content-id")
+            .and_then(|value| value.to_str().ok())
+            .map(|value| value.trim().trim_start_matches('<').trim_end_matches('>'))
+            .map(str::to_string);
+        let data = field.bytes().await.map_err(IntoResponse::into_response)?;
+
+        uploads.push((
+            index,
+            AttachmentRequest::from_upload(filename, content_type, content_id, &data),
+        ));
+    }
+
+    let mut payload = payload.ok_or_else(|| {
+        invalid(format!(
+            "Multipart request must include a {} field",
+            MULTIPART_PAYLOAD_FIELD
+        ))
+    })?;
+
+    // 업로드한 파일은 JSON에 지정한 첨부 파일 뒤에 추가
+    for (index, attachment) in uploads {
+        payload
+            .messages
+            .get_mut(index)
+            .ok_or_else(|| invalid(format!("Attachment refers to unknown message {}", index)))?
+            .attachments
+            .push(attachment);
+    }
+
+    Ok(payload)
 }
 
 pub async fn create_message(
     State(state): State<AppState>,
-    Json(payload): Json<CreateMessageRequest>,
+    Extension(auth): Extension<AuthContext>,
+    headers: HeaderMap,
+    MessagePayload(payload): MessagePayload,
 ) -> Result<Json<CreateMessageResponse>> {
     let start = Instant::now();
 
@@ -37,10 +289,101 @@ pub async fn create_message(
         .validate()
         .map_err(|e| AppError::Validation(e.to_string()))?;
 
+    // 토픽 접두사가 제한된 키는 해당 토픽으로만 발송 가능
+    for message in &payload.messages {
+        auth.check_topic(message.topic_id.as_deref().unwrap_or(""))?;
+    }
+
+    // 첨부 파일은 디코딩한 크기의 합으로 제한
+    let mut attachments = Vec::with_capacity(payload.messages.len());
+    let mut attachment_bytes = 0;
+    for message in &payload.messages {
+        let decoded = message
+            .attachments
+            .iter()
+            .map(AttachmentRequest::decode)
+            .collect::<std::result::Result<Vec<_>, _>>()
+            .map_err(AppError::Validation)?;
+        attachment_bytes += decoded.iter().map(Vec::len).sum::<usize>();
+        attachments.push(decoded);
+    }
+    if attachment_bytes > state.config.server.max_attachment_bytes {
+        return Err(AppError::Validation(format!(
+            "Total attachment size must be at most {} bytes",
+            state.config.server.max_attachment_bytes
+        )));
+    }
+
+    let idempotency_key = idempotency_key(&headers)?;
+
     let mut total_count = 0;
     let mut tx = state.db.begin().await?;
 
-    for message in payload.messages {
+    if let Some(key) = &idempotency_key {
+        let request_hash = hash_request(&payload)?;
+
+        // 동일 키로 진행 중인 트랜잭션이 있으면 커밋될 때까지 대기
+        let inserted = sqlx::query!(
+            "INSERT INTO idempotency_keys (tenant_id, key, request_hash, created_at)
+             VALUES ($1, $2, $3, $4)
+             ON CONFLICT (tenant_id, key) DO NOTHING",
+            auth.tenant_id,
+            key,
+            request_hash,
+            Utc::now()
+        )
+        .execute(&mut *tx)
+        .await?
+        .rows_affected();
+
+        if inserted == 0 {
+            let stored = sqlx::query!(
+                "SELECT request_hash, response FROM idempotency_keys
+                 WHERE tenant_id = $1 AND key = $2",
+                auth.tenant_id,
+                key
+            )
+            .fetch_one(&mut *tx)
+            .await?;
+
+            if stored.request_hash != request_hash {
+                return Err(AppError::Conflict(
+                    "Idempotency-Key was already used with a different request body".to_string(),
+                ));
+            }
+
+            let Some(response) = stored.response else {
+                return Err(AppError::Conflict(
+                    "A request with this Idempotency-Key is still being processed".to_string(),
+                ));
+            };
+
+            info!("📧 Replaying stored response for Idempotency-Key: {}", key);
+            return Ok(Json(serde_json::from_value(response)?));
+        }
+    }
+
+    // 템플릿을 해석한 뒤 수신자 변수 검증
+    // (재시도가 템플릿의 현재 상태에 영향받지 않도록 멱등성 키 확인 이후에 수행)
+    let mut sources = Vec::with_capacity(payload.messages.len());
+    for message in &payload.messages {
+        let source = resolve_message_source(&state.db, auth.tenant_id, message).await?;
+        message
+            .validate_variables(&source.subject, &source.content)
+            .map_err(AppError::Validation)?;
+        sources.push(source);
+    }
+
+    let recipient_count = payload
+        .messages
+        .iter()
+        .map(MessageRequest::recipient_count)
+        .sum();
+    reserve_recipient_quota(&mut tx, &auth, recipient_count).await?;
+
+    for ((message, source), attachment_data) in
+        payload.messages.into_iter().zip(sources).zip(attachments)
+    {
         let now = Utc::now();
         // Validate scheduled_at is not too far in the past
         if let Some(scheduled_at) = message.scheduled_at {
@@ -51,33 +394,73 @@ pub async fn create_message(
             }
         }
 
-        // 내용 생성
+        // 내용 생성 (템플릿 사용 시 제목/본문 대신 템플릿 버전을 참조)
+        let (subject, content) = match source.template_version_id {
+            Some(_) => (None, None),
+            None => (Some(source.subject), Some(source.content)),
+        };
+        let text_content = message.text_content.as_deref().map(str::trim);
+        let trimmed = |addresses: &[String]| -> Vec<String> {
+            addresses.iter().map(|a| a.trim().to_string()).collect()
+        };
         let content_id = sqlx::query_scalar!(
-            "INSERT INTO email_contents (subject, content, created_at, updated_at) 
-             VALUES ($1, $2, $3, $3) RETURNING id",
-            message.subject.trim(),
-            message.content.trim(),
+            "INSERT INTO email_contents (subject, content, text_content, template_version_id, from_email, from_name, reply_to, cc, bcc, headers, created_at, updated_at) 
+             VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $11) RETURNING id",
+            subject,
+            content,
+            text_content,
+            source.template_version_id,
+            message.from.as_ref().map(|from| from.email().trim()),
+            message.from.as_ref().and_then(|from| from.name()).map(str::trim),
+            &trimmed(&message.reply_to),
+            &trimmed(&message.cc),
+            &trimmed(&message.bcc),
+            serde_json::to_value(&message.headers)?,
             now
         )
         .fetch_one(&mut *tx)
         .await?;
 
+        // 첨부 파일은 내용마다 한 번만 저장하고 모든 수신자가 공유
+        for (attachment, data) in message.attachments.iter().zip(attachment_data) {
+            sqlx::query!(
+                "INSERT INTO email_attachments (content_id, filename, content_type, cid, size, data, created_at)
+                 VALUES ($1, $2, $3, $4, $5, $6, $7)",
+                content_id,
+                attachment.filename,
+                attachment.content_type(),
+                attachment.content_id,
+                data.len() as i32,
+                data,
+                now
+            )
+            .execute(&mut *tx)
+            .await?;
+        }
+
         // 이메일 요청 배치 삽입
         let scheduled_at = message.scheduled_at;
         let topic_id = message.topic_id.unwrap_or(String::new());
 
-        for email in &message.emails {
+        for recipient in &message.emails {
             let request_id = Uuid::now_v7();
+            let vars = match recipient.vars() {
+                vars if vars.is_empty() => None,
+                vars => Some(serde_json::to_value(vars)?),
+            };
 
             sqlx::query!(
-                "INSERT INTO email_requests (id, topic_id, to_email, content_id, scheduled_at, status, created_at, updated_at)
-                 VALUES ($1, $2, $3, $4, $5, $6, $7, $7)",
+                "INSERT INTO email_requests (id, tenant_id, topic_id, to_email, content_id, scheduled_at, status, priority, vars, created_at, updated_at)
+                 VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $10)",
                 request_id,
+                auth.tenant_id,
                 topic_id,
-                email.trim(),
+                recipient.email().trim(),
                 content_id,
                 scheduled_at,
                 EmailStatus::Created as i16,
+                message.priority as i16,
+                vars,
                 now
             )
             .execute(&mut *tx)
@@ -93,40 +476,361 @@ pub async fn create_message(
         );
     }
 
+    let elapsed = start.elapsed();
+    let response = CreateMessageResponse {
+        count: total_count,
+        elapsed: format!("{:?}", elapsed),
+    };
+
+    // 재시도 시 동일한 응답을 돌려주기 위해 같은 트랜잭션에서 응답 저장
+    if let Some(key) = &idempotency_key {
+        sqlx::query!(
+            "UPDATE idempotency_keys SET response = $1 WHERE tenant_id = $2 AND key = $3",
+            serde_json::to_value(&response)?,
+            auth.tenant_id,
+            key
+        )
+        .execute(&mut *tx)
+        .await?;
+    }
+
     tx.commit().await?;
 
-    let elapsed = start.elapsed();
     info!(
         "📧 Successfully created {} message requests in {:?}",
         total_count, elapsed
     );
 
-    Ok(Json(CreateMessageResponse {
-        count: total_count,
-        elapsed: format!("{:?}", elapsed),
+    Ok(Json(response))
+}
+
+pub async fn get_message(
+    State(state): State<AppState>,
+    Extension(auth): Extension<AuthContext>,
+    Path(request_id): Path<Uuid>,
+) -> Result<Json<MessageDetailResponse>> {
+    let request = sqlx::query_as!(
+        EmailRequest,
+        r#"
+        SELECT
+            id,
+            topic_id,
+            to_email,
+            content_id,
+            scheduled_at,
+            status as "status: EmailStatus",
+            priority as "priority: MessagePriority",
+            error,
+            attempts,
+            next_attempt_at,
+            attempt_history,
+            created_at,
+            updated_at
+        FROM email_requests
+        WHERE id = $1 AND tenant_id = $2
+        "#,
+        request_id,
+        auth.tenant_id
+    )
+    .fetch_optional(&state.db)
+    .await?
+    .filter(|request| auth.allows_topic(&request.topic_id))
+    .ok_or_else(|| AppError::NotFound(format!("Message request not found: {}", request_id)))?;
+
+    let content = sqlx::query!(
+        r#"
+        SELECT
+            COALESCE(ec.subject, tv.subject) as subject,
+            tv.template_id as "template_id?",
+            tv.version as "template_version?"
+        FROM email_contents ec
+        LEFT JOIN email_template_versions tv ON tv.id = ec.template_version_id
+        WHERE ec.id = $1
+        "#,
+        request.content_id
+    )
+    .fetch_optional(&state.db)
+    .await?;
+
+    // 요청에 기록된 결과 이벤트를 시간 순으로 조회
+    let events = sqlx::query_as!(
+        EmailResult,
+        "SELECT id, request_id, status, raw, ses_message_id, event_at, bounce_type, bounce_sub_type,
+                complaint_feedback_type, diagnostic, created_at, updated_at
+         FROM email_results
+         WHERE request_id = $1
+         ORDER BY created_at ASC, id ASC",
+        request_id
+    )
+    .fetch_all(&state.db)
+    .await?;
+
+    Ok(Json(MessageDetailResponse {
+        attempt_history: request.attempt_history.clone(),
+        message: MessageResponse::from(request),
+        subject: content.as_ref().and_then(|c| c.subject.clone()),
+        template_id: content.as_ref().and_then(|c| c.template_id),
+        template_version: content.as_ref().and_then(|c| c.template_version),
+        events: events.into_iter().map(MessageEventResponse::from).collect(),
+    }))
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ListMessagesQuery {
+    cursor: Option<Uuid>,
+    limit: Option<i64>,
+    status: Option<String>,
+    email: Option<String>,
+    created_from: Option<DateTime<Utc>>,
+    created_to: Option<DateTime<Utc>>,
+    updated_from: Option<DateTime<Utc>>,
+    updated_to: Option<DateTime<Utc>>,
+    result_status: Option<String>,
+}
+
+/// 과거형 결과 상태("bounced" 등)를 SES 이벤트 이름으로 정규화
+fn normalize_result_status(status: &str) -> String {
+    let status = status.trim().to_ascii_lowercase();
+    match status.as_str() {
+        "bounced" => "bounce".to_string(),
+        "delivered" => "delivery".to_string(),
+        "opened" => "open".to_string(),
+        "clicked" => "click".to_string(),
+        "complained" => "complaint".to_string(),
+        "rejected" => "reject".to_string(),
+        _ => status,
+    }
+}
+
+pub async fn list_topic_messages(
+    State(state): State<AppState>,
+    Extension(auth): Extension<AuthContext>,
+    Path(topic_id): Path<String>,
+    Query(query): Query<ListMessagesQuery>,
+) -> Result<Json<MessageListResponse>> {
+    auth.check_topic(&topic_id)?;
+
+    let limit = query.limit.unwrap_or(100);
+    if !(1..=1000).contains(&limit) {
+        return Err(AppError::Validation(
+            "limit must be between 1 and 1000".to_string(),
+        ));
+    }
+
+    let status = query
+        .status
+        .as_deref()
+        .map(str::parse::<EmailStatus>)
+        .transpose()
+        .map_err(AppError::Validation)?
+        .map(|status| status as i16);
+    let result_status = query.result_status.as_deref().map(normalize_result_status);
+    let email = query.email.as_deref().map(str::trim);
+
+    // UUIDv7 ID는 시간 순으로 정렬되므로 커서로 그대로 사용
+    // 다음 페이지 존재 여부 확인을 위해 limit + 1 개를 조회
+    let mut requests = sqlx::query_as!(
+        EmailRequest,
+        r#"
+        SELECT
+            er.id,
+            er.topic_id,
+            er.to_email,
+            er.content_id,
+            er.scheduled_at,
+            er.status as "status: EmailStatus",
+            er.priority as "priority: MessagePriority",
+            er.error,
+            er.attempts,
+            er.next_attempt_at,
+            er.attempt_history,
+            er.created_at,
+            er.updated_at
+        FROM email_requests er
+        WHERE er.tenant_id = $11
+          AND er.topic_id = $1
+          AND ($2::uuid IS NULL OR er.id > $2)
+          AND ($3::smallint IS NULL OR er.status = $3)
+          AND ($4::text IS NULL OR lower(er.to_email) = lower($4))
+          AND ($5::timestamptz IS NULL OR er.created_at >= $5)
+          AND ($6::timestamptz IS NULL OR er.created_at < $6)
+          AND ($7::timestamptz IS NULL OR er.updated_at >= $7)
+          AND ($8::timestamptz IS NULL OR er.updated_at < $8)
+          AND ($9::text IS NULL OR EXISTS (
+              SELECT 1 FROM email_results r
+              WHERE r.request_id = er.id AND lower(r.status) = $9
+          ))
+        ORDER BY er.id ASC
+        LIMIT $10
+        "#,
+        topic_id,
+        query.cursor,
+        status,
+        email,
+        query.created_from,
+        query.created_to,
+        query.updated_from,
+        query.updated_to,
+        result_status,
+        limit + 1,
+        auth.tenant_id
+    )
+    .fetch_all(&state.db)
+    .await?;
+
+    let next_cursor = if requests.len() as i64 > limit {
+        requests.truncate(limit as usize);
+        requests.last().map(|request| request.id)
+    } else {
+        None
+    };
+
+    Ok(Json(MessageListResponse {
+        items: requests.into_iter().map(MessageResponse::from).collect(),
+        next_cursor,
     }))
 }
 
+/// API로 중지된 요청에 기록되는 사유
+const STOPPED_BY_API: &str = "Stopped by API request";
+
+pub async fn stop_topic(
+    State(state): State<AppState>,
+    Extension(auth): Extension<AuthContext>,
+    Path(topic_id): Path<String>,
+) -> Result<Json<StopMessageResponse>> {
+    auth.check_topic(&topic_id)?;
+
+    // 아직 발송되지 않은 요청만 전이 규칙에 따라 중지
+    let stoppable = EmailStatus::sources_of(EmailStatus::Stopped);
+
+    let count = sqlx::query!(
+        "UPDATE email_requests SET status = $1, error = $2, updated_at = $3
+         WHERE tenant_id = $6 AND topic_id = $4 AND status = ANY($5)",
+        EmailStatus::Stopped as i16,
+        STOPPED_BY_API,
+        Utc::now(),
+        topic_id,
+        &stoppable,
+        auth.tenant_id
+    )
+    .execute(&state.db)
+    .await?
+    .rows_affected();
+
+    info!(
+        "📧 Stopped {} email requests for topic_id: {}",
+        count, topic_id
+    );
+
+    Ok(Json(StopMessageResponse { count }))
+}
+
+pub async fn cancel_message(
+    State(state): State<AppState>,
+    Extension(auth): Extension<AuthContext>,
+    Path(request_id): Path<Uuid>,
+) -> Result<Json<StopMessageResponse>> {
+    let stoppable = EmailStatus::sources_of(EmailStatus::Stopped);
+
+    let count = sqlx::query!(
+        "UPDATE email_requests SET status = $1, error = $2, updated_at = $3
+         WHERE id = $4
+           AND tenant_id = $6
+           AND ($7::text IS NULL OR starts_with(topic_id, $7))
+           AND status = ANY($5)",
+        EmailStatus::Stopped as i16,
+        STOPPED_BY_API,
+        Utc::now(),
+        request_id,
+        &stoppable,
+        auth.tenant_id,
+        auth.topic_prefix
+    )
+    .execute(&state.db)
+    .await?
+    .rows_affected();
+
+    if count == 0 {
+        // 이미 종료된 요청인지, 존재하지 않는 요청인지 구분
+        let exists = sqlx::query_scalar!(
+            "SELECT EXISTS(
+                SELECT 1 FROM email_requests
+                WHERE id = $1 AND tenant_id = $2 AND ($3::text IS NULL OR starts_with(topic_id, $3))
+            )",
+            request_id,
+            auth.tenant_id,
+            auth.topic_prefix
+        )
+        .fetch_one(&state.db)
+        .await?
+        .unwrap_or(false);
+
+        if !exists {
+            return Err(AppError::NotFound(format!(
+                "Message request not found: {}",
+                request_id
+            )));
+        }
+    }
+
+    info!(
+        "📧 Cancel requested for request_id: {} (stopped={})",
+        request_id, count
+    );
+
+    Ok(Json(StopMessageResponse { count }))
+}
+
 pub async fn create_open_event(
     State(state): State<AppState>,
+    connect_info: Option<ConnectInfo<SocketAddr>>,
+    headers: HeaderMap,
     Query(params): Query<HashMap<String, String>>,
 ) -> Result<Response> {
     // 픽셀 응답을 차단하지 않도록 비동기적으로 열림 이벤트 기록
+    // 서명이 유효하지 않은 요청은 기록하지 않지만 픽셀은 그대로 반환
     if let Some(request_id) = params.get("requestId") {
+        let token = params.get("token").map_or("", String::as_str);
         if let Ok(uuid) = Uuid::parse_str(request_id) {
+            if let Err(e) = state
+                .signer
+                .verify(OPEN_TOKEN_PURPOSE, &uuid.to_string(), token)
+            {
+                warn!("📧 Rejected open event for {}: {}", uuid, e);
+                return Ok(tracking_pixel_response());
+            }
+
+            let user_agent = headers
+                .get(header::USER_AGENT)
+                .and_then(|value| value.to_str().ok())
+                .map(String::from);
+            let ip = tracking::client_ip(
+                &headers,
+                &state.config.server.trusted_proxy_headers,
+                connect_info.map(|ConnectInfo(addr)| addr.ip()),
+            );
+            let machine = tracking::classify_open(user_agent.as_deref(), ip);
+            let now = Utc::now();
+
             let db = state.db.clone();
             tokio::spawn(async move {
+                // 요청별로 사람의 오픈과 기계 오픈을 각각 한 번만 기록
                 let result = sqlx::query!(
-                    "INSERT INTO email_results (request_id, status, raw, created_at, updated_at)
-                     VALUES ($1, $2, $3, $4, $4)
-                     ON CONFLICT (request_id, status) DO NOTHING",
+                    "INSERT INTO email_results (request_id, status, raw, machine_open, created_at, updated_at)
+                     VALUES ($1, $2, $3, $4, $5, $5)
+                     ON CONFLICT (request_id, machine_open) WHERE status = 'Open' DO NOTHING",
                     uuid,
                     "Open",
                     serde_json::json!({
-                        "timestamp": Utc::now(),
-                        "user_agent": "tracking-pixel"
+                        "timestamp": now,
+                        "user_agent": user_agent,
+                        "ip": ip.map(|ip| ip.to_string()),
+                        "machine": machine.map(|machine| machine.as_str()),
                     }),
-                    Utc::now()
+                    machine.is_some(),
+                    now
                 )
                 .execute(&db)
                 .await;
@@ -151,6 +855,10 @@ pub async fn create_open_event(
         }
     }
 
+    Ok(tracking_pixel_response())
+}
+
+fn tracking_pixel_response() -> Response {
     // 1x1 투명 PNG 즉시 반환
     const TRANSPARENT_PNG: &[u8] = &[
         0x89, 0x50, 0x4E, 0x47, 0x0D, 0x0A, 0x1A, 0x0A, // PNG signature
@@ -169,7 +877,7 @@ pub async fn create_open_event(
         0xAE, 0x42, 0x60, 0x82, // CRC
     ];
 
-    Ok((
+    (
         StatusCode::OK,
         [
             ("Content-Type", "image/png"),
@@ -178,110 +886,605 @@ pub async fn create_open_event(
         ],
         TRANSPARENT_PNG,
     )
-        .into_response())
+        .into_response()
 }
 
-pub async fn create_result_event(
+/// 발송 시점과 동일하게 본문을 렌더링하기 위한 요청 및 내용 조회
+async fn find_request_with_content(
+    db: &PgPool,
+    request_id: Uuid,
+) -> Result<Option<EmailRequestWithContent>> {
+    let request = sqlx::query_as!(
+        EmailRequestWithContent,
+        r#"
+        SELECT
+            er.id,
+            er.topic_id,
+            er.to_email,
+            er.content_id,
+            er.scheduled_at,
+            er.status as "status: EmailStatus",
+            er.priority as "priority: MessagePriority",
+            er.error,
+            er.attempts,
+            er.vars,
+            er.created_at,
+            er.updated_at,
+            COALESCE(ec.subject, tv.subject) as subject,
+            COALESCE(ec.content, tv.content) as content,
+            ec.text_content,
+            ec.from_email,
+            ec.from_name,
+            ec.reply_to,
+            ec.cc,
+            ec.bcc,
+            ec.headers,
+            (SELECT COALESCE(jsonb_agg(jsonb_build_object(
+                'id', a.id, 'filename', a.filename, 'content_type', a.content_type,
+                'cid', a.cid, 'size', a.size) ORDER BY a.id), '[]'::jsonb)
+             FROM email_attachments a WHERE a.content_id = ec.id) as "attachments!"
+        FROM email_requests er
+        JOIN email_contents ec ON ec.id = er.content_id
+        LEFT JOIN email_template_versions tv ON tv.id = ec.template_version_id
+        WHERE er.id = $1
+        "#,
+        request_id
+    )
+    .fetch_optional(db)
+    .await?;
+
+    Ok(request)
+}
+
+pub async fn create_click_event(
     State(state): State<AppState>,
-    body: Bytes,
-) -> Result<Json<Value>> {
-    // from_slice는 바이트 슬라이스를 파싱
-    let payload: SnsMessage = serde_json::from_slice(&body).map_err(|e| {
-        warn!("Failed to parse SNS message body: {}", e);
-        AppError::Validation("Failed to parse SNS message body".to_string())
-    })?;
+    Query(params): Query<HashMap<String, String>>,
+) -> Result<Response> {
+    let request_id = params
+        .get("requestId")
+        .and_then(|id| Uuid::parse_str(id).ok())
+        .ok_or_else(|| AppError::Validation("Invalid requestId".to_string()))?;
+    let link_index = params
+        .get("link")
+        .and_then(|link| link.parse::<usize>().ok())
+        .ok_or_else(|| AppError::Validation("Invalid link".to_string()))?;
 
-    if payload.message_type == "SubscriptionConfirmation" {
-        info!(
-            "SNS subscription confirmation required: {:?}",
-            payload.subscribe_url
-        );
-        return Ok(Json(
-            serde_json::json!({"message": "Subscription confirmation required"}),
-        ));
-    }
+    let request = find_request_with_content(&state.db, request_id)
+        .await?
+        .ok_or_else(|| AppError::NotFound(format!("Message request not found: {}", request_id)))?;
 
-    if payload.message_type != "Notification" {
-        info!(
-            "Non-notification SNS message received: {}",
-            payload.message_type
-        );
-        return Ok(Json(
-            serde_json::json!({"message": "Other message type received"}),
-        ));
-    }
+    // 쿼리 파라미터가 아닌 본문에서 다시 찾은 주소로만 리다이렉트 (오픈 리다이렉트 방지)
+    let url = request
+        .tracked_links()
+        .into_iter()
+        .nth(link_index)
+        .ok_or_else(|| AppError::NotFound(format!("Link not found: {}", link_index)))?;
 
-    let ses_notification: SesNotification =
-        serde_json::from_str(&payload.message).map_err(|_| {
-            warn!("Non-SES notification received");
-            AppError::Validation("Non-SES notification received".to_string())
-        })?;
+    let redirect = (
+        StatusCode::FOUND,
+        [
+            (header::LOCATION, url.clone()),
+            (
+                header::CACHE_CONTROL,
+                "no-cache, no-store, must-revalidate".to_string(),
+            ),
+        ],
+    )
+        .into_response();
 
-    let request_id = ses_notification
-        .mail
-        .tags
-        .get("request_id")
-        .and_then(|tags| tags.first())
-        .ok_or_else(|| AppError::Validation("Custom message_id not found in tags".to_string()))?;
+    // 서명이 유효하지 않으면 기록하지 않고 리다이렉트만 수행
+    let token = params.get("token").map_or("", String::as_str);
+    if let Err(e) = state.signer.verify(
+        CLICK_TOKEN_PURPOSE,
+        &click_token_subject(request_id, link_index),
+        token,
+    ) {
+        warn!("📧 Rejected click event for {}: {}", request_id, e);
+        return Ok(redirect);
+    }
 
-    let uuid = Uuid::parse_str(request_id)?;
+    // 리다이렉트를 지연시키지 않도록 비동기적으로 클릭 이벤트 기록
+    let db = state.db.clone();
+    let raw = serde_json::json!({
+        "timestamp": Utc::now(),
+        "link_index": link_index,
+        "url": url,
+    });
+    tokio::spawn(async move {
+        let result = sqlx::query!(
+            "INSERT INTO email_results (request_id, status, raw, created_at, updated_at)
+             VALUES ($1, $2, $3, $4, $4)",
+            request_id,
+            "Click",
+            raw,
+            Utc::now()
+        )
+        .execute(&db)
+        .await;
 
-    sqlx::query!(
-        "INSERT INTO email_results (request_id, status, raw, created_at, updated_at)
-         VALUES ($1, $2, $3, $4, $4)",
-        uuid,
-        ses_notification.notification_type,
-        serde_json::to_value(&payload.message)?,
-        Utc::now()
-    )
-    .execute(&state.db)
-    .await?;
+        match result {
+            Ok(_) => {
+                debug!(
+                    "📧 Email click event recorded for request_id: {}, link: {}",
+                    request_id, link_index
+                );
+            }
+            Err(e) => {
+                warn!("📧 Failed to create click event for {}: {}", request_id, e);
+            }
+        }
+    });
 
-    info!(
-        "SES result event saved: request_id={}, notification_type={}",
-        request_id, ses_notification.notification_type
-    );
+    Ok(redirect)
+}
 
-    Ok(Json(serde_json::json!({"message": "OK"})))
+/// 수신 거부 대상 요청
+struct UnsubscribeTarget {
+    id: Uuid,
+    tenant_id: i32,
+    topic_id: String,
+    to_email: String,
 }
 
-pub async fn get_result_count(
-    State(state): State<AppState>,
-    Path(topic_id): Path<String>,
-) -> Result<Json<ResultCountResponse>> {
-    // 토픽이 존재하는지 확인
-    let total_requests = sqlx::query_scalar!(
-        "SELECT COUNT(*) FROM email_requests WHERE topic_id = $1",
-        topic_id
-    )
-    .fetch_one(&state.db)
-    .await?
-    .unwrap_or(0);
+/// 서명된 수신 거부 링크의 대상 요청 조회 (서명이 유효하지 않거나 요청이 없으면 None)
+async fn find_unsubscribe_target(
+    state: &AppState,
+    params: &HashMap<String, String>,
+) -> Result<Option<UnsubscribeTarget>> {
+    let Some(request_id) = params
+        .get("requestId")
+        .and_then(|id| Uuid::parse_str(id).ok())
+    else {
+        return Ok(None);
+    };
 
-    if total_requests == 0 {
-        return Ok(Json(ResultCountResponse {
-            request: RequestCounts {
-                total: 0,
-                created: 0,
-                sent: 0,
-                failed: 0,
-                stopped: 0,
-            },
-            result: ResultCounts {
-                statuses: HashMap::new(),
-            },
-        }));
+    let token = params.get("token").map_or("", String::as_str);
+    if let Err(e) = state
+        .signer
+        .verify(UNSUBSCRIBE_TOKEN_PURPOSE, &request_id.to_string(), token)
+    {
+        warn!("📧 Rejected unsubscribe link for {}: {}", request_id, e);
+        return Ok(None);
     }
 
-    // 상태별 요청 수 가져오기
-    let request_counts = sqlx::query!(
-        "SELECT status, COUNT(*) as count FROM email_requests WHERE topic_id = $1 GROUP BY status",
-        topic_id
+    let target = sqlx::query_as!(
+        UnsubscribeTarget,
+        "SELECT id, tenant_id, topic_id, to_email FROM email_requests WHERE id = $1",
+        request_id
     )
-    .fetch_all(&state.db)
+    .fetch_optional(&state.db)
     .await?;
 
-    let mut req_counts = RequestCounts {
+    Ok(target)
+}
+
+fn unsubscribe_page(status: StatusCode, message: &str, confirm: bool) -> Response {
+    // 같은 주소로 POST하여 수신 거부 확정 (링크 검사기의 GET 요청으로 처리되지 않도록)
+    let form = if confirm {
+        r#"<form method="post"><button type="submit">Unsubscribe</button></form>"#
+    } else {
+        ""
+    };
+    let body = format!(
+        r#"<!DOCTYPE html><html><head><meta charset="utf-8"><meta name="viewport" content="width=device-width, initial-scale=1"><title>Unsubscribe</title></head><body><p>{}</p>{}</body></html>"#,
+        message, form
+    );
+
+    (
+        status,
+        [
+            (header::CONTENT_TYPE, "text/html; charset=utf-8"),
+            (header::CACHE_CONTROL, "no-store"),
+        ],
+        body,
+    )
+        .into_response()
+}
+
+const INVALID_UNSUBSCRIBE_LINK: &str = "This unsubscribe link is invalid.";
+
+/// 수신 거부 확인 페이지
+pub async fn get_unsubscribe(
+    State(state): State<AppState>,
+    Query(params): Query<HashMap<String, String>>,
+) -> Result<Response> {
+    if find_unsubscribe_target(&state, &params).await?.is_none() {
+        return Ok(unsubscribe_page(
+            StatusCode::BAD_REQUEST,
+            INVALID_UNSUBSCRIBE_LINK,
+            false,
+        ));
+    }
+
+    Ok(unsubscribe_page(
+        StatusCode::OK,
+        "Do you want to stop receiving these emails?",
+        true,
+    ))
+}
+
+/// 수신 거부 처리 (확인 페이지의 폼 제출 또는 RFC 8058 원클릭 요청)
+///
+/// 요청의 토픽에 대한 수신 거부로 기록되며, 토픽이 없는 요청은 전체 수신 거부로 기록됩니다.
+pub async fn create_unsubscribe(
+    State(state): State<AppState>,
+    Query(params): Query<HashMap<String, String>>,
+    body: Bytes,
+) -> Result<Response> {
+    let Some(target) = find_unsubscribe_target(&state, &params).await? else {
+        return Ok(unsubscribe_page(
+            StatusCode::BAD_REQUEST,
+            INVALID_UNSUBSCRIBE_LINK,
+            false,
+        ));
+    };
+
+    let email = normalize_email(&target.to_email);
+    let topic_id = Some(target.topic_id).filter(|topic_id| !topic_id.is_empty());
+    let one_click = body
+        .split(|&byte| byte == b'&')
+        .any(|pair| pair == b"List-Unsubscribe=One-Click");
+    let now = Utc::now();
+
+    let mut tx = state.db.begin().await?;
+    let inserted = sqlx::query!(
+        "INSERT INTO suppressions (tenant_id, email, topic_id, reason, request_id, created_at, updated_at)
+         VALUES ($6, $1, $2, $3, $4, $5, $5)
+         ON CONFLICT (tenant_id, email, COALESCE(topic_id, '')) DO NOTHING",
+        email,
+        topic_id,
+        SUPPRESSION_REASON_UNSUBSCRIBE,
+        target.id,
+        now,
+        target.tenant_id
+    )
+    .execute(&mut *tx)
+    .await?
+    .rows_affected();
+
+    // 토픽 통계에 반영되도록 결과 이벤트로도 기록 (중복 요청은 한 번만)
+    if inserted > 0 {
+        sqlx::query!(
+            "INSERT INTO email_results (request_id, status, raw, created_at, updated_at)
+             VALUES ($1, $2, $3, $4, $4)",
+            target.id,
+            "Unsubscribe",
+            serde_json::json!({
+                "timestamp": now,
+                "one_click": one_click,
+                "topic_id": topic_id,
+            }),
+            now
+        )
+        .execute(&mut *tx)
+        .await?;
+    }
+    tx.commit().await?;
+
+    info!(
+        "🚫 Recipient unsubscribed: request_id={}, topic_id={:?}, one_click={}",
+        target.id, topic_id, one_click
+    );
+
+    Ok(unsubscribe_page(
+        StatusCode::OK,
+        "You have been unsubscribed.",
+        false,
+    ))
+}
+
+/// 발송기가 첨부 파일을 내려받는 엔드포인트 (발행 페이로드의 서명된 주소로만 접근)
+pub async fn get_attachment(
+    State(state): State<AppState>,
+    Path(attachment_id): Path<i32>,
+    Query(params): Query<HashMap<String, String>>,
+) -> Result<Response> {
+    let token = params.get("token").map_or("", String::as_str);
+    if let Err(e) = state
+        .signer
+        .verify(ATTACHMENT_TOKEN_PURPOSE, &attachment_id.to_string(), token)
+    {
+        warn!(
+            "📎 Rejected attachment download for {}: {}",
+            attachment_id, e
+        );
+        return Err(AppError::Forbidden("Invalid attachment token".to_string()));
+    }
+
+    let attachment = sqlx::query!(
+        "SELECT filename, content_type, data FROM email_attachments WHERE id = $1",
+        attachment_id
+    )
+    .fetch_optional(&state.db)
+    .await?
+    .ok_or_else(|| AppError::NotFound(format!("Attachment not found: {}", attachment_id)))?;
+
+    Ok((
+        [
+            (header::CONTENT_TYPE, attachment.content_type),
+            (
+                header::CONTENT_DISPOSITION,
+                format!(
+                    "attachment; filename*=UTF-8''{}",
+                    percent_encode(&attachment.filename)
+                ),
+            ),
+            (header::CACHE_CONTROL, "private, no-store".to_string()),
+            // 요청자가 지정한 Content-Type을 브라우저가 다른 형식으로 해석하지 않도록 함
+            (header::X_CONTENT_TYPE_OPTIONS, "nosniff".to_string()),
+        ],
+        attachment.data,
+    )
+        .into_response())
+}
+
+/// RFC 5987 파라미터 값 인코딩
+fn percent_encode(value: &str) -> String {
+    value
+        .bytes()
+        .map(|byte| match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => {
+                (byte as char).to_string()
+            }
+            _ => format!("%{:02X}", byte),
+        })
+        .collect()
+}
+
+pub async fn create_result_event(
+    State(state): State<AppState>,
+    body: Bytes,
+) -> Result<Json<Value>> {
+    // from_slice는 바이트 슬라이스를 파싱
+    let payload: SnsMessage = serde_json::from_slice(&body).map_err(|e| {
+        warn!("Failed to parse SNS message body: {}", e);
+        AppError::Validation("Failed to parse SNS message body".to_string())
+    })?;
+
+    // 서명되지 않았거나 허용되지 않은 토픽의 메시지는 거부
+    if state.config.sns.verify_signatures {
+        if let Err(e) = state.sns.verify(&payload).await {
+            warn!("🔒 Rejected SNS message {}: {}", payload.message_id, e);
+            return Err(match e {
+                // 인증서를 가져오지 못한 경우 SNS가 재전송하도록 5xx 반환
+                SnsVerificationError::CertificateFetch(_) => AppError::Internal(e.to_string()),
+                _ => AppError::Forbidden("Invalid SNS message signature".to_string()),
+            });
+        }
+    }
+
+    match payload.message_type.as_str() {
+        "SubscriptionConfirmation" => return confirm_sns_subscription(&state, &payload).await,
+        "UnsubscribeConfirmation" => return record_sns_unsubscription(&state, &payload).await,
+        _ => {}
+    }
+
+    if payload.message_type != "Notification" {
+        info!(
+            "Non-notification SNS message received: {}",
+            payload.message_type
+        );
+        return Ok(Json(
+            serde_json::json!({"message": "Other message type received"}),
+        ));
+    }
+
+    // 원본 객체는 그대로 JSONB로 저장하고, 주요 필드는 타입 모델로 추출
+    let raw: Value = serde_json::from_str(&payload.message).map_err(|_| {
+        warn!("Non-JSON SNS notification received");
+        AppError::Validation("Non-SES notification received".to_string())
+    })?;
+    let event = SesEvent::deserialize(&raw).map_err(|e| {
+        warn!("Non-SES notification received: {}", e);
+        AppError::Validation("Non-SES notification received".to_string())
+    })?;
+
+    let request_id = event
+        .request_id()
+        .ok_or_else(|| AppError::Validation("Custom message_id not found in tags".to_string()))?;
+
+    let uuid = Uuid::parse_str(request_id)?;
+    let mut tx = state.db.begin().await?;
+
+    sqlx::query!(
+        "INSERT INTO email_results (request_id, status, raw, ses_message_id, event_at, bounce_type, bounce_sub_type, complaint_feedback_type, diagnostic, created_at, updated_at)
+         VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $10)",
+        uuid,
+        event.event_type.as_str(),
+        raw,
+        event.mail.message_id,
+        event.occurred_at(),
+        event.bounce_type(),
+        event.bounce_sub_type(),
+        event.complaint_feedback_type(),
+        event.diagnostic(),
+        Utc::now()
+    )
+    .execute(&mut *tx)
+    .await?;
+
+    // 영구 반송/수신 거부 주소는 요청 테넌트의 모든 토픽에서 발송 중단
+    let suppressed: Vec<String> = event
+        .suppressed_recipients()
+        .into_iter()
+        .map(normalize_email)
+        .collect();
+    if !suppressed.is_empty() {
+        let reason = match event.event_type {
+            SesEventType::Complaint => SUPPRESSION_REASON_COMPLAINT,
+            _ => SUPPRESSION_REASON_BOUNCE,
+        };
+        let inserted = sqlx::query!(
+            "INSERT INTO suppressions (tenant_id, email, topic_id, reason, request_id, created_at, updated_at)
+             SELECT er.tenant_id, s.email, NULL, $2, er.id, $4, $4
+             FROM UNNEST($1::text[]) AS s(email)
+             JOIN email_requests er ON er.id = $3
+             ON CONFLICT (tenant_id, email, COALESCE(topic_id, '')) DO NOTHING",
+            &suppressed,
+            reason,
+            uuid,
+            Utc::now()
+        )
+        .execute(&mut *tx)
+        .await?
+        .rows_affected();
+
+        if inserted > 0 {
+            info!(
+                "🚫 Suppressed {} recipient(s) after {}: request_id={}",
+                inserted, event.event_type, request_id
+            );
+        }
+    }
+
+    tx.commit().await?;
+
+    info!(
+        "SES result event saved: request_id={}, event_type={}",
+        request_id, event.event_type
+    );
+
+    Ok(Json(serde_json::json!({"message": "OK"})))
+}
+
+/// SNS 구독 상태 (sns_subscriptions.status)
+const SNS_SUBSCRIPTION_PENDING: &str = "PendingConfirmation";
+const SNS_SUBSCRIPTION_CONFIRMED: &str = "Confirmed";
+const SNS_SUBSCRIPTION_UNSUBSCRIBED: &str = "Unsubscribed";
+
+/// 구독 요청 기록 후, 자동 확인 대상 토픽이면 SubscribeURL을 호출하여 확인
+async fn confirm_sns_subscription(state: &AppState, payload: &SnsMessage) -> Result<Json<Value>> {
+    let topic_arn = payload
+        .topic_arn
+        .as_deref()
+        .ok_or_else(|| AppError::Validation("TopicArn is required".to_string()))?;
+
+    sqlx::query!(
+        "INSERT INTO sns_subscriptions (topic_arn, status, created_at, updated_at)
+         VALUES ($1, $2, $3, $3)
+         ON CONFLICT (topic_arn) DO UPDATE SET status = EXCLUDED.status, updated_at = EXCLUDED.updated_at",
+        topic_arn,
+        SNS_SUBSCRIPTION_PENDING,
+        Utc::now()
+    )
+    .execute(&state.db)
+    .await?;
+
+    if !state.sns.should_auto_confirm(topic_arn) {
+        info!(
+            "SNS subscription confirmation required: topic_arn={}, subscribe_url={:?}",
+            topic_arn, payload.subscribe_url
+        );
+        return Ok(Json(
+            serde_json::json!({"message": "Subscription confirmation required"}),
+        ));
+    }
+
+    let subscription_arn = state.sns.confirm_subscription(payload).await.map_err(|e| {
+        error!(
+            "❌ Failed to confirm SNS subscription for {}: {}",
+            topic_arn, e
+        );
+        AppError::Internal("Failed to confirm SNS subscription".to_string())
+    })?;
+
+    let now = Utc::now();
+    sqlx::query!(
+        "UPDATE sns_subscriptions
+         SET status = $2, subscription_arn = $3, confirmed_at = $4, unsubscribed_at = NULL, updated_at = $4
+         WHERE topic_arn = $1",
+        topic_arn,
+        SNS_SUBSCRIPTION_CONFIRMED,
+        subscription_arn,
+        now
+    )
+    .execute(&state.db)
+    .await?;
+
+    info!(
+        "✅ SNS subscription confirmed: topic_arn={}, subscription_arn={}",
+        topic_arn, subscription_arn
+    );
+
+    Ok(Json(
+        serde_json::json!({"message": "Subscription confirmed"}),
+    ))
+}
+
+/// 구독 해제 알림 기록 (이후 SES 이벤트가 더 이상 수신되지 않음)
+async fn record_sns_unsubscription(state: &AppState, payload: &SnsMessage) -> Result<Json<Value>> {
+    let topic_arn = payload
+        .topic_arn
+        .as_deref()
+        .ok_or_else(|| AppError::Validation("TopicArn is required".to_string()))?;
+
+    let now = Utc::now();
+    sqlx::query!(
+        "INSERT INTO sns_subscriptions (topic_arn, status, unsubscribed_at, created_at, updated_at)
+         VALUES ($1, $2, $3, $3, $3)
+         ON CONFLICT (topic_arn) DO UPDATE
+         SET status = EXCLUDED.status, unsubscribed_at = EXCLUDED.unsubscribed_at, updated_at = EXCLUDED.updated_at",
+        topic_arn,
+        SNS_SUBSCRIPTION_UNSUBSCRIBED,
+        now
+    )
+    .execute(&state.db)
+    .await?;
+
+    warn!(
+        "⚠️ SNS subscription removed, SES events will no longer be received: topic_arn={}",
+        topic_arn
+    );
+
+    Ok(Json(serde_json::json!({"message": "OK"})))
+}
+
+pub async fn get_result_count(
+    State(state): State<AppState>,
+    Extension(auth): Extension<AuthContext>,
+    Path(topic_id): Path<String>,
+) -> Result<Json<ResultCountResponse>> {
+    auth.check_topic(&topic_id)?;
+
+    // 토픽이 존재하는지 확인
+    let total_requests = sqlx::query_scalar!(
+        "SELECT COUNT(*) FROM email_requests WHERE tenant_id = $1 AND topic_id = $2",
+        auth.tenant_id,
+        topic_id
+    )
+    .fetch_one(&state.db)
+    .await?
+    .unwrap_or(0);
+
+    if total_requests == 0 {
+        return Ok(Json(ResultCountResponse {
+            request: RequestCounts {
+                total: 0,
+                created: 0,
+                sent: 0,
+                failed: 0,
+                stopped: 0,
+            },
+            result: ResultCounts {
+                statuses: HashMap::new(),
+                opens: OpenCounts::default(),
+                clicks: ClickCounts::default(),
+            },
+        }));
+    }
+
+    // 상태별 요청 수 가져오기
+    let request_counts = sqlx::query!(
+        "SELECT status, COUNT(*) as count FROM email_requests
+         WHERE tenant_id = $1 AND topic_id = $2
+         GROUP BY status",
+        auth.tenant_id,
+        topic_id
+    )
+    .fetch_all(&state.db)
+    .await?;
+
+    let mut req_counts = RequestCounts {
         total: total_requests,
         created: 0,
         sent: 0,
@@ -304,8 +1507,9 @@ pub async fn get_result_count(
     let result_counts = sqlx::query!(
         "SELECT status, COUNT(DISTINCT request_id) as count 
          FROM email_results 
-         WHERE request_id IN (SELECT id FROM email_requests WHERE topic_id = $1)
+         WHERE request_id IN (SELECT id FROM email_requests WHERE tenant_id = $1 AND topic_id = $2)
          GROUP BY status",
+        auth.tenant_id,
         topic_id
     )
     .fetch_all(&state.db)
@@ -316,9 +1520,73 @@ pub async fn get_result_count(
         statuses.insert(row.status, row.count.unwrap_or(0));
     }
 
+    // 사람/기계 오픈 수 (둘 다 있는 요청은 사람의 오픈으로 집계)
+    let open_counts = sqlx::query!(
+        r#"
+        SELECT
+            COUNT(DISTINCT r.request_id) FILTER (WHERE NOT r.machine_open) as "human!",
+            COUNT(DISTINCT r.request_id) as "total!"
+        FROM email_results r
+        JOIN email_requests er ON er.id = r.request_id
+        WHERE er.tenant_id = $1
+          AND er.topic_id = $2
+          AND r.status = 'Open'
+          AND r.machine_open IS NOT NULL
+        "#,
+        auth.tenant_id,
+        topic_id
+    )
+    .fetch_one(&state.db)
+    .await?;
+    let opens = OpenCounts {
+        human: open_counts.human,
+        machine: open_counts.total - open_counts.human,
+    };
+
+    // 클릭 추적 링크별 클릭 수 (수신자별로 주소가 다른 링크는 url을 비움)
+    let link_counts = sqlx::query!(
+        r#"
+        SELECT
+            (r.raw->>'link_index')::int as "link_index!",
+            CASE WHEN COUNT(DISTINCT r.raw->>'url') = 1 THEN MIN(r.raw->>'url') END as url,
+            COUNT(*) as "total!",
+            COUNT(DISTINCT r.request_id) as "unique!"
+        FROM email_results r
+        JOIN email_requests er ON er.id = r.request_id
+        WHERE er.tenant_id = $1
+          AND er.topic_id = $2
+          AND r.status = 'Click'
+          AND r.raw ? 'link_index'
+        GROUP BY 1
+        ORDER BY 1
+        "#,
+        auth.tenant_id,
+        topic_id
+    )
+    .fetch_all(&state.db)
+    .await?;
+
+    let links: Vec<LinkClickCount> = link_counts
+        .into_iter()
+        .map(|row| LinkClickCount {
+            index: row.link_index,
+            url: row.url,
+            total: row.total,
+            unique: row.unique,
+        })
+        .collect();
+    let clicks = ClickCounts {
+        total: links.iter().map(|link| link.total).sum(),
+        links,
+    };
+
     Ok(Json(ResultCountResponse {
         request: req_counts,
-        result: ResultCounts { statuses },
+        result: ResultCounts {
+            statuses,
+            opens,
+            clicks,
+        },
     }))
 }
 
@@ -327,11 +1595,9 @@ pub struct SentCountQuery {
     hours: Option<i32>,
 }
 
-pub async fn get_sent_count(
-    State(state): State<AppState>,
-    Query(query): Query<SentCountQuery>,
-) -> Result<Json<SentCountResponse>> {
-    let hours = query.hours.unwrap_or(24);
+/// 발송 수 집계 구간의 시작 시각 (기본 24시간, 최대 168시간)
+fn sent_window_start(hours: Option<i32>) -> Result<(i32, DateTime<Utc>)> {
+    let hours = hours.unwrap_or(24);
 
     if hours <= 0 || hours > 168 {
         return Err(AppError::Validation(
@@ -339,13 +1605,26 @@ pub async fn get_sent_count(
         ));
     }
 
-    let start_time = Utc::now() - chrono::Duration::hours(hours as i64);
+    Ok((hours, Utc::now() - chrono::Duration::hours(hours as i64)))
+}
+
+pub async fn get_sent_count(
+    State(state): State<AppState>,
+    Extension(auth): Extension<AuthContext>,
+    Query(query): Query<SentCountQuery>,
+) -> Result<Json<SentCountResponse>> {
+    let (hours, start_time) = sent_window_start(query.hours)?;
 
     let count = sqlx::query_scalar!(
         "SELECT COUNT(*) FROM email_requests 
-         WHERE updated_at > $1 AND status = $2",
+         WHERE tenant_id = $1
+           AND ($4::text IS NULL OR starts_with(topic_id, $4))
+           AND updated_at > $2
+           AND status = $3",
+        auth.tenant_id,
         start_time,
-        EmailStatus::Sent as i16
+        EmailStatus::Sent as i16,
+        auth.topic_prefix
     )
     .fetch_one(&state.db)
     .await?
@@ -359,6 +1638,782 @@ pub async fn get_sent_count(
     Ok(Json(SentCountResponse { count }))
 }
 
+/// 수신 도메인별 발송 수 (발송 시각 기준, 상위 50개)
+pub async fn get_sent_count_by_domain(
+    State(state): State<AppState>,
+    Extension(auth): Extension<AuthContext>,
+    Query(query): Query<SentCountQuery>,
+) -> Result<Json<DomainSentCountResponse>> {
+    let (_, start_time) = sent_window_start(query.hours)?;
+
+    let items = sqlx::query_as!(
+        DomainSentCount,
+        r#"
+        SELECT
+            lower(split_part(to_email, '@', 2)) as "domain!",
+            COUNT(*) as "count!"
+        FROM email_requests
+        WHERE tenant_id = $1
+          AND ($4::text IS NULL OR starts_with(topic_id, $4))
+          AND updated_at > $2
+          AND status = $3
+        GROUP BY 1
+        ORDER BY 2 DESC, 1
+        LIMIT 50
+        "#,
+        auth.tenant_id,
+        start_time,
+        EmailStatus::Sent as i16,
+        auth.topic_prefix
+    )
+    .fetch_all(&state.db)
+    .await?;
+
+    Ok(Json(DomainSentCountResponse { items }))
+}
+
+fn conflict_on_unique_violation(e: sqlx::Error, message: &str) -> AppError {
+    match &e {
+        sqlx::Error::Database(db_error) if db_error.is_unique_violation() => {
+            AppError::Conflict(message.to_string())
+        }
+        _ => AppError::Database(e),
+    }
+}
+
+async fn find_template(db: &PgPool, tenant_id: i32, template_id: i32) -> Result<EmailTemplate> {
+    sqlx::query_as!(
+        EmailTemplate,
+        "SELECT id, name, description, latest_version, created_at, updated_at
+         FROM email_templates
+         WHERE id = $1 AND tenant_id = $2 AND deleted_at IS NULL",
+        template_id,
+        tenant_id
+    )
+    .fetch_optional(db)
+    .await?
+    .ok_or_else(|| AppError::NotFound(format!("Template not found: {}", template_id)))
+}
+
+/// 템플릿 버전 조회 (버전 생략 시 최신 버전, 삭제된 템플릿은 제외)
+async fn find_template_version(
+    db: &PgPool,
+    tenant_id: i32,
+    template_id: i32,
+    version: Option<i32>,
+) -> Result<EmailTemplateVersion> {
+    sqlx::query_as!(
+        EmailTemplateVersion,
+        "SELECT tv.id, tv.template_id, tv.version, tv.subject, tv.content, tv.created_at
+         FROM email_template_versions tv
+         JOIN email_templates t ON t.id = tv.template_id
+         WHERE t.id = $1
+           AND t.tenant_id = $3
+           AND t.deleted_at IS NULL
+           AND tv.version = COALESCE($2, t.latest_version)",
+        template_id,
+        version,
+        tenant_id
+    )
+    .fetch_optional(db)
+    .await?
+    .ok_or_else(|| match version {
+        Some(version) => AppError::NotFound(format!(
+            "Template version not found: {} v{}",
+            template_id, version
+        )),
+        None => AppError::NotFound(format!("Template not found: {}", template_id)),
+    })
+}
+
+async fn insert_template_version(
+    tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+    template_id: i32,
+    version: i32,
+    subject: &str,
+    content: &str,
+) -> Result<EmailTemplateVersion> {
+    let version = sqlx::query_as!(
+        EmailTemplateVersion,
+        "INSERT INTO email_template_versions (template_id, version, subject, content, created_at)
+         VALUES ($1, $2, $3, $4, $5)
+         RETURNING id, template_id, version, subject, content, created_at",
+        template_id,
+        version,
+        subject.trim(),
+        content.trim(),
+        Utc::now()
+    )
+    .fetch_one(&mut **tx)
+    .await?;
+
+    Ok(version)
+}
+
+pub async fn create_template(
+    State(state): State<AppState>,
+    Extension(auth): Extension<AuthContext>,
+    Json(payload): Json<CreateTemplateRequest>,
+) -> Result<Json<TemplateDetailResponse>> {
+    payload
+        .validate()
+        .map_err(|e| AppError::Validation(e.to_string()))?;
+
+    let now = Utc::now();
+    let mut tx = state.db.begin().await?;
+
+    let template = sqlx::query_as!(
+        EmailTemplate,
+        "INSERT INTO email_templates (tenant_id, name, description, latest_version, created_at, updated_at)
+         VALUES ($1, $2, $3, 1, $4, $4)
+         RETURNING id, name, description, latest_version, created_at, updated_at",
+        auth.tenant_id,
+        payload.name,
+        payload.description,
+        now
+    )
+    .fetch_one(&mut *tx)
+    .await
+    .map_err(|e| conflict_on_unique_violation(e, "Template name already exists"))?;
+
+    let version =
+        insert_template_version(&mut tx, template.id, 1, &payload.subject, &payload.content)
+            .await?;
+
+    tx.commit().await?;
+
+    info!(
+        "📝 Template created: id={}, name={}",
+        template.id, template.name
+    );
+
+    Ok(Json(TemplateDetailResponse {
+        template: template.into(),
+        latest: version.into(),
+    }))
+}
+
+pub async fn list_templates(
+    State(state): State<AppState>,
+    Extension(auth): Extension<AuthContext>,
+) -> Result<Json<TemplateListResponse>> {
+    let templates = sqlx::query_as!(
+        EmailTemplate,
+        "SELECT id, name, description, latest_version, created_at, updated_at
+         FROM email_templates
+         WHERE tenant_id = $1 AND deleted_at IS NULL
+         ORDER BY name ASC",
+        auth.tenant_id
+    )
+    .fetch_all(&state.db)
+    .await?;
+
+    Ok(Json(TemplateListResponse {
+        items: templates.into_iter().map(TemplateResponse::from).collect(),
+    }))
+}
+
+pub async fn get_template(
+    State(state): State<AppState>,
+    Extension(auth): Extension<AuthContext>,
+    Path(template_id): Path<i32>,
+) -> Result<Json<TemplateDetailResponse>> {
+    let template = find_template(&state.db, auth.tenant_id, template_id).await?;
+    let version = find_template_version(
+        &state.db,
+        auth.tenant_id,
+        template.id,
+        Some(template.latest_version),
+    )
+    .await?;
+
+    Ok(Json(TemplateDetailResponse {
+        template: template.into(),
+        latest: version.into(),
+    }))
+}
+
+pub async fn update_template(
+    State(state): State<AppState>,
+    Extension(auth): Extension<AuthContext>,
+    Path(template_id): Path<i32>,
+    Json(payload): Json<UpdateTemplateRequest>,
+) -> Result<Json<TemplateDetailResponse>> {
+    payload
+        .validate()
+        .map_err(|e| AppError::Validation(e.to_string()))?;
+
+    let mut tx = state.db.begin().await?;
+
+    // 기존 버전은 수정하지 않고 새 버전을 추가 (행 잠금으로 버전 번호 직렬화)
+    let template = sqlx::query_as!(
+        EmailTemplate,
+        "UPDATE email_templates
+         SET latest_version = latest_version + 1,
+             description = COALESCE($2, description),
+             updated_at = $3
+         WHERE id = $1 AND tenant_id = $4 AND deleted_at IS NULL
+         RETURNING id, name, description, latest_version, created_at, updated_at",
+        template_id,
+        payload.description,
+        Utc::now(),
+        auth.tenant_id
+    )
+    .fetch_optional(&mut *tx)
+    .await?
+    .ok_or_else(|| AppError::NotFound(format!("Template not found: {}", template_id)))?;
+
+    let version = insert_template_version(
+        &mut tx,
+        template.id,
+        template.latest_version,
+        &payload.subject,
+        &payload.content,
+    )
+    .await?;
+
+    tx.commit().await?;
+
+    info!(
+        "📝 Template updated: id={}, version={}",
+        template.id, template.latest_version
+    );
+
+    Ok(Json(TemplateDetailResponse {
+        template: template.into(),
+        latest: version.into(),
+    }))
+}
+
+pub async fn delete_template(
+    State(state): State<AppState>,
+    Extension(auth): Extension<AuthContext>,
+    Path(template_id): Path<i32>,
+) -> Result<StatusCode> {
+    // 발송 대기 중인 요청이 버전을 참조할 수 있으므로 소프트 삭제
+    let now = Utc::now();
+    let rows_affected = sqlx::query!(
+        "UPDATE email_templates SET deleted_at = $2, updated_at = $2
+         WHERE id = $1 AND tenant_id = $3 AND deleted_at IS NULL",
+        template_id,
+        now,
+        auth.tenant_id
+    )
+    .execute(&state.db)
+    .await?
+    .rows_affected();
+
+    if rows_affected == 0 {
+        return Err(AppError::NotFound(format!(
+            "Template not found: {}",
+            template_id
+        )));
+    }
+
+    info!("📝 Template deleted: id={}", template_id);
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+pub async fn list_template_versions(
+    State(state): State<AppState>,
+    Extension(auth): Extension<AuthContext>,
+    Path(template_id): Path<i32>,
+) -> Result<Json<TemplateVersionListResponse>> {
+    let template = find_template(&state.db, auth.tenant_id, template_id).await?;
+
+    let versions = sqlx::query_as!(
+        EmailTemplateVersion,
+        "SELECT id, template_id, version, subject, content, created_at
+         FROM email_template_versions
+         WHERE template_id = $1
+         ORDER BY version DESC",
+        template.id
+    )
+    .fetch_all(&state.db)
+    .await?;
+
+    Ok(Json(TemplateVersionListResponse {
+        items: versions
+            .into_iter()
+            .map(TemplateVersionResponse::from)
+            .collect(),
+    }))
+}
+
+pub async fn get_template_version(
+    State(state): State<AppState>,
+    Extension(auth): Extension<AuthContext>,
+    Path((template_id, version)): Path<(i32, i32)>,
+) -> Result<Json<TemplateVersionResponse>> {
+    let version =
+        find_template_version(&state.db, auth.tenant_id, template_id, Some(version)).await?;
+    Ok(Json(version.into()))
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ListSuppressionsQuery {
+    cursor: Option<i32>,
+    limit: Option<i64>,
+    email: Option<String>,
+    topic_id: Option<String>,
+    /// true면 전역 항목만, false면 토픽 항목만 조회
+    global: Option<bool>,
+}
+
+pub async fn list_suppressions(
+    State(state): State<AppState>,
+    Extension(auth): Extension<AuthContext>,
+    Query(query): Query<ListSuppressionsQuery>,
+) -> Result<Json<SuppressionListResponse>> {
+    let limit = query.limit.unwrap_or(100);
+    if !(1..=1000).contains(&limit) {
+        return Err(AppError::Validation(
+            "limit must be between 1 and 1000".to_string(),
+        ));
+    }
+    let email = query.email.as_deref().map(normalize_email);
+
+    let mut suppressions = sqlx::query_as!(
+        Suppression,
+        r#"
+        SELECT id, email, topic_id, reason, request_id, created_at, updated_at
+        FROM suppressions
+        WHERE tenant_id = $6
+          AND ($7::text IS NULL OR starts_with(topic_id, $7))
+          AND ($1::int IS NULL OR id > $1)
+          AND ($2::text IS NULL OR email = $2)
+          AND ($3::text IS NULL OR topic_id = $3)
+          AND ($4::boolean IS NULL OR (topic_id IS NULL) = $4)
+        ORDER BY id ASC
+        LIMIT $5
+        "#,
+        query.cursor,
+        email,
+        query.topic_id,
+        query.global,
+        limit + 1,
+        auth.tenant_id,
+        auth.topic_prefix
+    )
+    .fetch_all(&state.db)
+    .await?;
+
+    let next_cursor = if suppressions.len() as i64 > limit {
+        suppressions.truncate(limit as usize);
+        suppressions.last().map(|suppression| suppression.id)
+    } else {
+        None
+    };
+
+    Ok(Json(SuppressionListResponse {
+        items: suppressions
+            .into_iter()
+            .map(SuppressionResponse::from)
+            .collect(),
+        next_cursor,
+    }))
+}
+
+pub async fn create_suppression(
+    State(state): State<AppState>,
+    Extension(auth): Extension<AuthContext>,
+    Json(payload): Json<CreateSuppressionRequest>,
+) -> Result<Json<SuppressionResponse>> {
+    payload
+        .validate()
+        .map_err(|e| AppError::Validation(e.to_string()))?;
+    auth.check_topic(payload.topic_id.as_deref().unwrap_or(""))?;
+
+    let suppression = sqlx::query_as!(
+        Suppression,
+        "INSERT INTO suppressions (tenant_id, email, topic_id, reason, created_at, updated_at)
+         VALUES ($1, $2, $3, $4, $5, $5)
+         RETURNING id, email, topic_id, reason, request_id, created_at, updated_at",
+        auth.tenant_id,
+        normalize_email(&payload.email),
+        payload.topic_id,
+        payload
+            .reason
+            .as_deref()
+            .unwrap_or(SUPPRESSION_REASON_MANUAL),
+        Utc::now()
+    )
+    .fetch_one(&state.db)
+    .await
+    .map_err(|e| conflict_on_unique_violation(e, "Suppression already exists"))?;
+
+    info!(
+        "🚫 Suppression created: email={}, topic_id={:?}",
+        suppression.email, suppression.topic_id
+    );
+
+    Ok(Json(suppression.into()))
+}
+
+pub async fn delete_suppression(
+    State(state): State<AppState>,
+    Extension(auth): Extension<AuthContext>,
+    Path(suppression_id): Path<i32>,
+) -> Result<StatusCode> {
+    let deleted = sqlx::query!(
+        "DELETE FROM suppressions
+         WHERE id = $1 AND tenant_id = $2 AND ($3::text IS NULL OR starts_with(topic_id, $3))",
+        suppression_id,
+        auth.tenant_id,
+        auth.topic_prefix
+    )
+    .execute(&state.db)
+    .await?
+    .rows_affected();
+
+    if deleted == 0 {
+        return Err(AppError::NotFound("Suppression not found".to_string()));
+    }
+
+    info!("🚫 Suppression deleted: id={}", suppression_id);
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// JSON(`{"suppressions": [...]}`) 또는 CSV(`Content-Type: text/csv`) 일괄 등록
+///
+/// 이미 등록된 주소는 기존 항목을 유지하고 건너뜁니다.
+pub async fn import_suppressions(
+    State(state): State<AppState>,
+    Extension(auth): Extension<AuthContext>,
+    headers: HeaderMap,
+    body: Bytes,
+) -> Result<Json<ImportSuppressionsResponse>> {
+    let is_csv = headers
+        .get(header::CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .is_some_and(|value| value.starts_with("text/csv"));
+
+    let payload = if is_csv {
+        let body = std::str::from_utf8(&body)
+            .map_err(|_| AppError::Validation("CSV body must be UTF-8".to_string()))?;
+        ImportSuppressionsRequest::from_csv(body)
+    } else {
+        serde_json::from_slice(&body)?
+    };
+    payload
+        .validate()
+        .map_err(|e| AppError::Validation(e.to_string()))?;
+
+    let total = payload.suppressions.len() as u64;
+    let mut emails = Vec::with_capacity(payload.suppressions.len());
+    let mut topic_ids = Vec::with_capacity(payload.suppressions.len());
+    let mut reasons = Vec::with_capacity(payload.suppressions.len());
+    for suppression in payload.suppressions {
+        auth.check_topic(suppression.topic_id.as_deref().unwrap_or(""))?;
+        emails.push(normalize_email(&suppression.email));
+        topic_ids.push(suppression.topic_id);
+        reasons.push(
+            suppression
+                .reason
+                .unwrap_or_else(|| SUPPRESSION_REASON_MANUAL.to_string()),
+        );
+    }
+
+    let imported = sqlx::query!(
+        "INSERT INTO suppressions (tenant_id, email, topic_id, reason, created_at, updated_at)
+         SELECT $5, email, topic_id, reason, $4, $4
+         FROM UNNEST($1::text[], $2::text[], $3::text[]) AS s(email, topic_id, reason)
+         ON CONFLICT (tenant_id, email, COALESCE(topic_id, '')) DO NOTHING",
+        &emails,
+        &topic_ids as &[Option<String>],
+        &reasons,
+        Utc::now(),
+        auth.tenant_id
+    )
+    .execute(&state.db)
+    .await?
+    .rows_affected();
+
+    info!(
+        "🚫 Suppressions imported: imported={}, skipped={}",
+        imported,
+        total - imported
+    );
+
+    Ok(Json(ImportSuppressionsResponse {
+        imported,
+        skipped: total - imported,
+    }))
+}
+
+/// 목록 조회와 같은 조건으로 전체 항목을 CSV로 내보내기
+pub async fn export_suppressions(
+    State(state): State<AppState>,
+    Extension(auth): Extension<AuthContext>,
+    Query(query): Query<ListSuppressionsQuery>,
+) -> Result<Response> {
+    let email = query.email.as_deref().map(normalize_email);
+
+    let suppressions = sqlx::query_as!(
+        Suppression,
+        r#"
+        SELECT id, email, topic_id, reason, request_id, created_at, updated_at
+        FROM suppressions
+        WHERE tenant_id = $4
+          AND ($5::text IS NULL OR starts_with(topic_id, $5))
+          AND ($1::text IS NULL OR email = $1)
+          AND ($2::text IS NULL OR topic_id = $2)
+          AND ($3::boolean IS NULL OR (topic_id IS NULL) = $3)
+        ORDER BY id ASC
+        "#,
+        email,
+        query.topic_id,
+        query.global,
+        auth.tenant_id,
+        auth.topic_prefix
+    )
+    .fetch_all(&state.db)
+    .await?;
+
+    let mut csv = format!("{}\n", SUPPRESSION_CSV_HEADER);
+    for suppression in suppressions {
+        csv.push_str(&format!(
+            "{},{},{},{}\n",
+            suppression.email,
+            suppression.topic_id.unwrap_or_default(),
+            suppression.reason,
+            suppression.created_at.to_rfc3339()
+        ));
+    }
+
+    Ok((
+        [
+            (header::CONTENT_TYPE, "text/csv; charset=utf-8"),
+            (
+                header::CONTENT_DISPOSITION,
+                "attachment; filename=\"suppressions.csv\"",
+            ),
+        ],
+        csv,
+    )
+        .into_response())
+}
+
+pub async fn list_tenants(State(state): State<AppState>) -> Result<Json<TenantListResponse>> {
+    let tenants = sqlx::query_as!(
+        Tenant,
+        "SELECT id, name, created_at FROM tenants ORDER BY id ASC"
+    )
+    .fetch_all(&state.db)
+    .await?;
+
+    Ok(Json(TenantListResponse {
+        items: tenants.into_iter().map(TenantResponse::from).collect(),
+    }))
+}
+
+pub async fn create_tenant(
+    State(state): State<AppState>,
+    Json(payload): Json<CreateTenantRequest>,
+) -> Result<Json<TenantResponse>> {
+    payload
+        .validate()
+        .map_err(|e| AppError::Validation(e.to_string()))?;
+
+    let tenant = sqlx::query_as!(
+        Tenant,
+        "INSERT INTO tenants (name, created_at) VALUES ($1, $2)
+         RETURNING id, name, created_at",
+        payload.name,
+        Utc::now()
+    )
+    .fetch_one(&state.db)
+    .await
+    .map_err(|e| conflict_on_unique_violation(e, "Tenant name already exists"))?;
+
+    info!("🏢 Tenant created: id={}, name={}", tenant.id, tenant.name);
+
+    Ok(Json(tenant.into()))
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ListApiKeysQuery {
+    tenant_id: Option<i32>,
+}
+
+pub async fn list_api_keys(
+    State(state): State<AppState>,
+    Query(query): Query<ListApiKeysQuery>,
+) -> Result<Json<ApiKeyListResponse>> {
+    let api_keys = sqlx::query_as!(
+        ApiKey,
+        "SELECT id, tenant_id, name, key_prefix, scopes, topic_prefix, expires_at,
+                requests_per_sec, daily_recipient_quota, monthly_recipient_quota,
+                last_used_at, revoked_at, created_at
+         FROM api_keys
+         WHERE ($1::int IS NULL OR tenant_id = $1)
+         ORDER BY id ASC",
+        query.tenant_id
+    )
+    .fetch_all(&state.db)
+    .await?;
+
+    Ok(Json(ApiKeyListResponse {
+        items: api_keys.into_iter().map(ApiKeyResponse::from).collect(),
+    }))
+}
+
+/// API 키 발급 (원문은 해시만 저장하므로 응답에서 한 번만 반환)
+pub async fn create_api_key(
+    State(state): State<AppState>,
+    Extension(auth): Extension<AuthContext>,
+    Json(payload): Json<CreateApiKeyRequest>,
+) -> Result<Json<CreateApiKeyResponse>> {
+    payload
+        .validate()
+        .map_err(|e| AppError::Validation(e.to_string()))?;
+
+    let tenant_exists = sqlx::query_scalar!(
+        "SELECT EXISTS(SELECT 1 FROM tenants WHERE id = $1)",
+        payload.tenant_id
+    )
+    .fetch_one(&state.db)
+    .await?
+    .unwrap_or(false);
+    if !tenant_exists {
+        return Err(AppError::NotFound(format!(
+            "Tenant not found: {}",
+            payload.tenant_id
+        )));
+    }
+
+    if payload
+        .expires_at
+        .is_some_and(|expires_at| expires_at <= Utc::now())
+    {
+        return Err(AppError::Validation(
+            "expiresAt must be in the future".to_string(),
+        ));
+    }
+
+    // 권한은 정규화한 이름으로 중복 없이 저장
+    let mut scopes: Vec<String> = Vec::with_capacity(payload.scopes.len());
+    for scope in &payload.scopes {
+        let scope = scope
+            .parse::<ApiScope>()
+            .map_err(AppError::Validation)?
+            .to_string();
+        if !scopes.contains(&scope) {
+            scopes.push(scope);
+        }
+    }
+
+    // admin 권한은 테넌트와 관계없이 모든 테넌트를 관리하므로 부트스트랩 키만 발급 가능
+    if scopes.contains(&ApiScope::Admin.to_string()) && !auth.is_bootstrap() {
+        return Err(AppError::Forbidden(
+            "Only the bootstrap API key can create keys with the admin scope".to_string(),
+        ));
+    }
+
+    let key = generate_api_key()
+        .map_err(|_| AppError::Internal("Failed to generate API key".to_string()))?;
+
+    let api_key = sqlx::query_as!(
+        ApiKey,
+        "INSERT INTO api_keys (tenant_id, name, key_prefix, key_hash, scopes, topic_prefix, expires_at,
+                               requests_per_sec, daily_recipient_quota, monthly_recipient_quota, created_at)
+         VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11)
+         RETURNING id, tenant_id, name, key_prefix, scopes, topic_prefix, expires_at,
+                   requests_per_sec, daily_recipient_quota, monthly_recipient_quota,
+                   last_used_at, revoked_at, created_at",
+        payload.tenant_id,
+        payload.name.trim(),
+        api_key_prefix(&key),
+        hash_api_key(&key),
+        &scopes,
+        payload.topic_prefix,
+        payload.expires_at,
+        payload.requests_per_sec,
+        payload.daily_recipient_quota,
+        payload.monthly_recipient_quota,
+        Utc::now()
+    )
+    .fetch_one(&state.db)
+    .await?;
+
+    info!(
+        "🔑 API key created: id={}, tenant_id={}, prefix={}, scopes={:?}",
+        api_key.id, api_key.tenant_id, api_key.key_prefix, api_key.scopes
+    );
+
+    Ok(Json(CreateApiKeyResponse {
+        api_key: api_key.into(),
+        key,
+    }))
+}
+
+/// API 키 폐기 (사용 기록을 남기기 위해 삭제하지 않고 폐기 시각만 기록)
+pub async fn revoke_api_key(
+    State(state): State<AppState>,
+    Path(api_key_id): Path<i32>,
+) -> Result<Json<ApiKeyResponse>> {
+    let api_key = sqlx::query_as!(
+        ApiKey,
+        "UPDATE api_keys SET revoked_at = COALESCE(revoked_at, $2)
+         WHERE id = $1
+         RETURNING id, tenant_id, name, key_prefix, scopes, topic_prefix, expires_at,
+                   requests_per_sec, daily_recipient_quota, monthly_recipient_quota,
+                   last_used_at, revoked_at, created_at",
+        api_key_id,
+        Utc::now()
+    )
+    .fetch_optional(&state.db)
+    .await?
+    .ok_or_else(|| AppError::NotFound(format!("API key not found: {}", api_key_id)))?;
+
+    info!(
+        "🔑 API key revoked: id={}, tenant_id={}",
+        api_key.id, api_key.tenant_id
+    );
+
+    Ok(Json(api_key.into()))
+}
+
+/// 요청한 API 키의 제한과 현재 사용량
+pub async fn get_usage(
+    State(state): State<AppState>,
+    Extension(auth): Extension<AuthContext>,
+) -> Result<Json<UsageResponse>> {
+    let today = Utc::now().date_naive();
+
+    let usage = sqlx::query!(
+        r#"SELECT
+               COALESCE(SUM(recipients) FILTER (WHERE day = $2), 0)::bigint as "daily!",
+               COALESCE(SUM(recipients), 0)::bigint as "monthly!"
+           FROM api_key_usage
+           WHERE api_key_id = $1 AND day >= $3"#,
+        auth.api_key_id,
+        today,
+        month_start(today)
+    )
+    .fetch_one(&state.db)
+    .await?;
+
+    Ok(Json(UsageResponse {
+        api_key_id: auth.api_key_id,
+        requests_per_sec: auth.limits.requests_per_sec,
+        daily: UsagePeriodResponse {
+            used: usage.daily,
+            limit: auth.limits.daily_recipients,
+            resets_at: start_of_day(next_day_start(today)),
+        },
+        monthly: UsagePeriodResponse {
+            used: usage.monthly,
+            limit: auth.limits.monthly_recipients,
+            resets_at: start_of_day(next_month_start(today)),
+        },
+    }))
+}
+
 pub async fn health_check(State(state): State<AppState>) -> Result<Json<HealthResponse>> {
     // 데이터베이스 연결 테스트
     sqlx::query("SELECT 1").execute(&state.db).await?;
@@ -1,16 +1,70 @@
-use crate::{api::handlers::AppState, error::AppError};
+use crate::{
+    api::handlers::AppState,
+    error::AppError,
+    models::{
+        tenant::{hash_api_key, ApiScope, DEFAULT_TENANT_ID},
+        usage::ApiKeyLimits,
+    },
+};
 use axum::{
     extract::{Request, State},
     http::HeaderMap,
     middleware::Next,
     response::Response,
 };
+use chrono::Utc;
 use subtle::ConstantTimeEq;
 
+/// 마지막 사용 시각은 이 간격보다 자주 갱신하지 않음 (요청마다 쓰기 방지)
+const LAST_USED_UPDATE_INTERVAL_SECS: i64 = 60;
+
+/// 인증된 요청의 테넌트와 권한 (핸들러에서 `Extension<AuthContext>`로 사용)
+#[derive(Debug, Clone)]
+pub struct AuthContext {
+    pub tenant_id: i32,
+    /// 환경 변수 API_KEY로 인증한 경우 None
+    pub api_key_id: Option<i32>,
+    pub scopes: Vec<ApiScope>,
+    /// 설정되면 이 접두사로 시작하는 토픽만 다룰 수 있음
+    pub topic_prefix: Option<String>,
+    pub limits: ApiKeyLimits,
+}
+
+impl AuthContext {
+    pub fn has_scope(&self, scope: ApiScope) -> bool {
+        self.scopes
+            .iter()
+            .any(|granted| *granted == scope || *granted == ApiScope::Admin)
+    }
+
+    /// 환경 변수 API_KEY(부트스트랩 키)로 인증했는지 여부
+    pub fn is_bootstrap(&self) -> bool {
+        self.api_key_id.is_none()
+    }
+
+    pub fn allows_topic(&self, topic_id: &str) -> bool {
+        self.topic_prefix
+            .as_deref()
+            .is_none_or(|prefix| topic_id.starts_with(prefix))
+    }
+
+    /// 토픽 접두사 제한을 벗어난 토픽이면 403
+    pub fn check_topic(&self, topic_id: &str) -> Result<(), AppError> {
+        if self.allows_topic(topic_id) {
+            Ok(())
+        } else {
+            Err(AppError::Forbidden(format!(
+                "API key is not allowed to access topic: {}",
+                topic_id
+            )))
+        }
+    }
+}
+
 pub async fn auth_middleware(
     State(state): State<AppState>,
     headers: HeaderMap,
-    request: Request,
+    mut request: Request,
     next: Next,
 ) -> Result<Response, AppError> {
     let api_key = headers
@@ -18,17 +72,127 @@ pub async fn auth_middleware(
         .and_then(|value| value.to_str().ok())
         .unwrap_or("");
 
-    let expected_key = &state.config.security.api_key;
+    if api_key.is_empty() {
+        tracing::warn!("🔒 권한 없는 API 접근 시도");
+        return Err(AppError::Unauthorized);
+    }
 
     // 타이밍 공격을 방지하기 위해 상수 시간 비교 사용
-    if api_key.len() == expected_key.len()
-        && api_key.as_bytes().ct_eq(expected_key.as_bytes()).into()
-    {
-        Ok(next.run(request).await)
+    let is_bootstrap_key = state
+        .config
+        .security
+        .api_key
+        .as_deref()
+        .is_some_and(|expected_key| {
+            api_key.len() == expected_key.len()
+                && bool::from(api_key.as_bytes().ct_eq(expected_key.as_bytes()))
+        });
+
+    let context = if is_bootstrap_key {
+        AuthContext {
+            tenant_id: DEFAULT_TENANT_ID,
+            api_key_id: None,
+            scopes: vec![ApiScope::Admin],
+            topic_prefix: None,
+            limits: ApiKeyLimits::default(),
+        }
     } else {
-        tracing::warn!("🔒 권한 없는 API 접근 시도");
-        Err(AppError::Unauthorized)
+        // 해시로 조회하므로 원문 비교가 필요 없음
+        let Some(key) = sqlx::query!(
+            "SELECT id, tenant_id, scopes, topic_prefix, requests_per_sec, daily_recipient_quota,
+                    monthly_recipient_quota, last_used_at
+             FROM api_keys
+             WHERE key_hash = $1
+               AND revoked_at IS NULL
+               AND (expires_at IS NULL OR expires_at > $2)",
+            hash_api_key(api_key),
+            Utc::now()
+        )
+        .fetch_optional(&state.db)
+        .await?
+        else {
+            tracing::warn!("🔒 권한 없는 API 접근 시도");
+            return Err(AppError::Unauthorized);
+        };
+
+        let now = Utc::now();
+        let stale = key.last_used_at.is_none_or(|last_used_at| {
+            now - last_used_at > chrono::Duration::seconds(LAST_USED_UPDATE_INTERVAL_SECS)
+        });
+        if stale {
+            let db = state.db.clone();
+            tokio::spawn(async move {
+                if let Err(e) = sqlx::query!(
+                    "UPDATE api_keys SET last_used_at = $2 WHERE id = $1",
+                    key.id,
+                    now
+                )
+                .execute(&db)
+                .await
+                {
+                    tracing::warn!("🔒 API 키 사용 시각 갱신 실패: {}", e);
+                }
+            });
+        }
+
+        let limits = ApiKeyLimits::resolve(
+            key.requests_per_sec,
+            key.daily_recipient_quota,
+            key.monthly_recipient_quota,
+            &state.config.limits,
+        );
+
+        // 키별 초당 요청 수 제한
+        if let Some(requests_per_sec) = limits.requests_per_sec {
+            if let Err(wait) = state.rate_limiter.check(key.id, requests_per_sec) {
+                tracing::warn!("🔒 API 키 요청 수 제한 초과: id={}", key.id);
+                return Err(AppError::TooManyRequests {
+                    message: "API key request rate limit exceeded".to_string(),
+                    retry_after_secs: wait.as_secs_f64().ceil().max(1.0) as u64,
+                });
+            }
+        }
+
+        AuthContext {
+            tenant_id: key.tenant_id,
+            api_key_id: Some(key.id),
+            // 알 수 없는 권한은 무시 (이전 버전에서 제거된 권한 등)
+            scopes: key
+                .scopes
+                .iter()
+                .filter_map(|scope| scope.parse().ok())
+                .collect(),
+            topic_prefix: key.topic_prefix,
+            limits,
+        }
+    };
+
+    request.extensions_mut().insert(context);
+    Ok(next.run(request).await)
+}
+
+/// 라우트에 필요한 권한이 없는 키의 요청을 거부 (`auth_middleware` 다음에 적용)
+///
+/// `middleware::from_fn_with_state(ApiScope::StatsRead, require_scope)`처럼 라우트별로 사용합니다.
+pub async fn require_scope(
+    State(scope): State<ApiScope>,
+    request: Request,
+    next: Next,
+) -> Result<Response, AppError> {
+    let allowed = request
+        .extensions()
+        .get::<AuthContext>()
+        .is_some_and(|context| context.has_scope(scope));
+
+    if !allowed {
+        tracing::warn!("🔒 권한이 없는 API 접근 시도: {}", scope);
+        return Err(AppError::Forbidden(format!(
+            "API key is missing required scope: {}",
+            scope
+        )));
     }
+
+    Ok(next.run(request).await)
 }
 
 #[cfg(test)]
@@ -36,6 +200,37 @@ mod tests {
     use super::*;
     use subtle::Choice;
 
+    #[test]
+    fn test_auth_context_scopes_and_topics() {
+        let context = AuthContext {
+            tenant_id: 2,
+            api_key_id: Some(3),
+            scopes: vec![ApiScope::StatsRead, ApiScope::MessagesRead],
+            topic_prefix: Some("marketing-".to_string()),
+            limits: ApiKeyLimits::default(),
+        };
+
+        assert!(context.has_scope(ApiScope::StatsRead));
+        assert!(!context.has_scope(ApiScope::MessagesSend));
+        assert!(context.allows_topic("marketing-spring"));
+        assert!(!context.allows_topic("billing"));
+        assert!(context.check_topic("").is_err());
+
+        let admin = AuthContext {
+            tenant_id: 1,
+            api_key_id: None,
+            scopes: vec![ApiScope::Admin],
+            topic_prefix: None,
+            limits: ApiKeyLimits::default(),
+        };
+        assert!(ApiScope::ALL
+            .into_iter()
+            .all(|scope| admin.has_scope(scope)));
+        assert!(admin.allows_topic(""));
+        assert!(admin.is_bootstrap());
+        assert!(!context.is_bootstrap());
+    }
+
     #[test]
     fn test_constant_time_comparison() {
         // 상수 시간 비교 함수 테스트
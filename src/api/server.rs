@@ -1,7 +1,14 @@
-use crate::{api::handlers, api::middleware::auth_middleware, config::AppConfig};
+use crate::{
+    api::handlers,
+    api::middleware::{auth_middleware, require_scope},
+    config::AppConfig,
+    models::tenant::ApiScope,
+    services::{rate_limit::KeyedRateLimiter, sns::SnsVerifier, token::TokenSigner},
+};
 use axum::{
+    extract::DefaultBodyLimit,
     middleware,
-    routing::{get, post},
+    routing::{delete, get, post, put},
     Router,
 };
 use sqlx::PgPool;
@@ -23,9 +30,12 @@ pub async fn run(db: PgPool, config: Arc<AppConfig>) -> anyhow::Result<()> {
     let listener = tokio::net::TcpListener::bind(addr).await?;
     info!("🌐 HTTP server listening on {}", addr);
 
-    axum::serve(listener, app)
-        .with_graceful_shutdown(shutdown_signal())
-        .await?;
+    axum::serve(
+        listener,
+        app.into_make_service_with_connect_info::<SocketAddr>(),
+    )
+    .with_graceful_shutdown(shutdown_signal())
+    .await?;
 
     info!("🌐 HTTP server shutdown complete");
     Ok(())
@@ -57,9 +67,21 @@ async fn shutdown_signal() {
     info!("🌐 Shutdown signal received, starting graceful shutdown");
 }
 
+/// 요청 본문 기본 제한 (axum 기본값과 동일)
+const DEFAULT_BODY_LIMIT: usize = 2 * 1024 * 1024;
+
 async fn create_app(db: PgPool, config: Arc<AppConfig>) -> Router {
+    // base64로 인코딩된 첨부 파일(또는 multipart 업로드)을 받을 수 있도록 메시지 생성 요청의 본문 제한 확대
+    let message_body_limit = config.server.max_attachment_bytes / 3 * 4 + DEFAULT_BODY_LIMIT;
+
     // 공유 상태 생성
-    let state = handlers::AppState { db, config };
+    let state = handlers::AppState {
+        db,
+        signer: Arc::new(TokenSigner::new(&config.security)),
+        sns: Arc::new(SnsVerifier::new(&config.sns)),
+        rate_limiter: Arc::new(KeyedRateLimiter::default()),
+        config,
+    };
 
     // 미들웨어 스택 생성
     let middleware_stack = ServiceBuilder::new()
@@ -85,11 +107,126 @@ async fn create_app(db: PgPool, config: Arc<AppConfig>) -> Router {
                 .allow_headers(Any),
         );
 
-    // 보호된 라우트 (API 키 필요)
-    let protected_routes = Router::new()
-        .route("/v1/messages", post(handlers::create_message))
+    // 보호된 라우트 (API 키 필요, 라우트별로 키에 필요한 권한을 검사)
+    let send_routes = Router::new()
+        .route(
+            "/v1/messages",
+            post(handlers::create_message).layer(DefaultBodyLimit::max(message_body_limit)),
+        )
+        .route(
+            "/v1/messages/:request_id/cancel",
+            post(handlers::cancel_message),
+        )
+        .route("/v1/topics/:topic_id/stop", post(handlers::stop_topic))
+        .route_layer(middleware::from_fn_with_state(
+            ApiScope::MessagesSend,
+            require_scope,
+        ));
+
+    let message_read_routes = Router::new()
+        .route("/v1/messages/:request_id", get(handlers::get_message))
+        .route(
+            "/v1/topics/:topic_id/messages",
+            get(handlers::list_topic_messages),
+        )
+        .route_layer(middleware::from_fn_with_state(
+            ApiScope::MessagesRead,
+            require_scope,
+        ));
+
+    let stats_routes = Router::new()
         .route("/v1/topics/:topic_id", get(handlers::get_result_count))
         .route("/v1/events/counts/sent", get(handlers::get_sent_count))
+        .route(
+            "/v1/events/counts/sent/domains",
+            get(handlers::get_sent_count_by_domain),
+        )
+        .route_layer(middleware::from_fn_with_state(
+            ApiScope::StatsRead,
+            require_scope,
+        ));
+
+    let template_read_routes = Router::new()
+        .route("/v1/templates", get(handlers::list_templates))
+        .route("/v1/templates/:template_id", get(handlers::get_template))
+        .route(
+            "/v1/templates/:template_id/versions",
+            get(handlers::list_template_versions),
+        )
+        .route(
+            "/v1/templates/:template_id/versions/:version",
+            get(handlers::get_template_version),
+        )
+        .route_layer(middleware::from_fn_with_state(
+            ApiScope::TemplatesRead,
+            require_scope,
+        ));
+
+    let template_write_routes = Router::new()
+        .route("/v1/templates", post(handlers::create_template))
+        .route(
+            "/v1/templates/:template_id",
+            put(handlers::update_template).delete(handlers::delete_template),
+        )
+        .route_layer(middleware::from_fn_with_state(
+            ApiScope::TemplatesWrite,
+            require_scope,
+        ));
+
+    let suppression_read_routes = Router::new()
+        .route("/v1/suppressions", get(handlers::list_suppressions))
+        .route(
+            "/v1/suppressions/export",
+            get(handlers::export_suppressions),
+        )
+        .route_layer(middleware::from_fn_with_state(
+            ApiScope::SuppressionsRead,
+            require_scope,
+        ));
+
+    let suppression_write_routes = Router::new()
+        .route("/v1/suppressions", post(handlers::create_suppression))
+        .route(
+            "/v1/suppressions/import",
+            post(handlers::import_suppressions),
+        )
+        .route(
+            "/v1/suppressions/:suppression_id",
+            delete(handlers::delete_suppression),
+        )
+        .route_layer(middleware::from_fn_with_state(
+            ApiScope::SuppressionsWrite,
+            require_scope,
+        ));
+
+    let admin_routes = Router::new()
+        .route(
+            "/v1/admin/tenants",
+            get(handlers::list_tenants).post(handlers::create_tenant),
+        )
+        .route(
+            "/v1/admin/api-keys",
+            get(handlers::list_api_keys).post(handlers::create_api_key),
+        )
+        .route(
+            "/v1/admin/api-keys/:api_key_id",
+            delete(handlers::revoke_api_key),
+        )
+        .route_layer(middleware::from_fn_with_state(
+            ApiScope::Admin,
+            require_scope,
+        ));
+
+    let protected_routes = Router::new()
+        .merge(send_routes)
+        .merge(message_read_routes)
+        .merge(stats_routes)
+        .merge(template_read_routes)
+        .merge(template_write_routes)
+        .merge(suppression_read_routes)
+        .merge(suppression_write_routes)
+        .merge(admin_routes)
+        .route("/v1/usage", get(handlers::get_usage))
         .layer(middleware::from_fn_with_state(
             state.clone(),
             auth_middleware,
@@ -98,7 +235,16 @@ async fn create_app(db: PgPool, config: Arc<AppConfig>) -> Router {
     // 공개 라우트
     let public_routes = Router::new()
         .route("/v1/events/open", get(handlers::create_open_event))
+        .route("/v1/events/click", get(handlers::create_click_event))
         .route("/v1/events/results", post(handlers::create_result_event))
+        .route(
+            "/v1/attachments/:attachment_id",
+            get(handlers::get_attachment),
+        )
+        .route(
+            "/v1/unsubscribe",
+            get(handlers::get_unsubscribe).post(handlers::create_unsubscribe),
+        )
         .route("/health", get(handlers::health_check));
 
     // 모든 라우트 결합
@@ -1,7 +1,8 @@
+use crate::models::email::MessagePriority;
 use anyhow::{Context, Result};
 use serde::Deserialize;
 use sqlx::{postgres::PgPoolOptions, PgPool};
-use std::time::Duration;
+use std::{str::FromStr, time::Duration};
 use tracing::info;
 
 #[derive(Debug, Clone, Deserialize)]
@@ -11,12 +12,18 @@ pub struct AppConfig {
     pub nats: NatsConfig,
     pub scheduler: SchedulerConfig,
     pub security: SecurityConfig,
+    pub sns: SnsConfig,
+    pub limits: LimitsConfig,
 }
 
 #[derive(Debug, Clone, Deserialize)]
 pub struct ServerConfig {
     pub port: u16,
     pub host: String,
+    /// 클라이언트 IP를 담고 있다고 신뢰하는 프록시 헤더 (앞에서부터 우선)
+    pub trusted_proxy_headers: Vec<String>,
+    /// 메시지 생성 요청 하나에 포함할 수 있는 첨부 파일의 총 크기 (디코딩 후)
+    pub max_attachment_bytes: usize,
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -32,29 +39,146 @@ pub struct DatabaseConfig {
 pub struct NatsConfig {
     pub url: String,
     pub stream: String,
+    /// 일반(대량 발송) 메시지를 발행할 subject
     pub subject: String,
+    /// 높은 우선순위(트랜잭션) 메시지를 발행할 subject
+    pub high_priority_subject: String,
+}
+
+impl NatsConfig {
+    pub fn subject_for(&self, priority: MessagePriority) -> &str {
+        match priority {
+            MessagePriority::Normal => &self.subject,
+            MessagePriority::High => &self.high_priority_subject,
+        }
+    }
+
+    /// 스트림이 받아야 하는 subject 목록
+    pub fn subjects(&self) -> Vec<String> {
+        let mut subjects = vec![self.subject.clone()];
+        if self.high_priority_subject != self.subject {
+            subjects.push(self.high_priority_subject.clone());
+        }
+        subjects
+    }
 }
 
 #[derive(Debug, Clone, Deserialize)]
 pub struct SchedulerConfig {
     pub batch_size: usize,
     pub interval_secs: u64,
+    pub processing_timeout_secs: u64,
+    pub max_recoveries: i16,
+    pub reaper_interval_secs: u64,
+    pub retry_max_attempts: i16,
+    pub retry_base_delay_secs: u64,
+    pub retry_max_delay_secs: u64,
+    /// 모든 발행 작업이 공유하는 초당 발행 수 제한 (0이면 제한 없음)
+    pub send_rate_per_sec: f64,
+    /// 동시에 실행할 발행 작업 수
+    pub publish_concurrency: usize,
+    /// 수신 도메인(또는 도메인 그룹)별 초당 발행 수 제한
+    pub domain_rate_limits: Vec<DomainRateLimit>,
+    /// 각 배치에서 높은 우선순위 요청에 먼저 배정하는 비율 (0.0 ~ 1.0)
+    pub high_priority_share: f64,
+}
+
+/// `gmail.com=20` 또는 `outlook.com|hotmail.com|live.com=10` 형식의 도메인 그룹별 제한
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct DomainRateLimit {
+    /// 통계와 로그에 표시할 이름 (`|`로 연결한 도메인 목록)
+    pub name: String,
+    pub domains: Vec<String>,
+    pub per_sec: f64,
+}
+
+impl FromStr for DomainRateLimit {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        let (name, per_sec) = s
+            .split_once('=')
+            .ok_or_else(|| format!("Invalid domain rate limit (expected domain=rate): {}", s))?;
+        let domains: Vec<String> = name
+            .to_lowercase()
+            .split('|')
+            .map(str::trim)
+            .filter(|domain| !domain.is_empty())
+            .map(String::from)
+            .collect();
+        let per_sec: f64 = per_sec
+            .trim()
+            .parse()
+            .map_err(|_| format!("Invalid domain rate limit value: {}", s))?;
+
+        if domains.is_empty() || per_sec.is_nan() || per_sec <= 0.0 {
+            return Err(format!("Invalid domain rate limit: {}", s));
+        }
+
+        Ok(Self {
+            name: domains.join("|"),
+            domains,
+            per_sec,
+        })
+    }
 }
 
 #[derive(Debug, Clone, Deserialize)]
 pub struct SecurityConfig {
-    pub api_key: String,
+    /// 기본 테넌트의 관리자 키 (테넌트와 API 키를 처음 발급할 때 사용, 설정하지 않으면 비활성화)
+    pub api_key: Option<String>,
+    /// 트래킹 토큰 서명 키
+    pub tracking_secret: String,
+    /// 키 교체 전 발송된 메일의 토큰 검증용 이전 키 목록
+    pub tracking_previous_secrets: Vec<String>,
+    /// 트래킹 토큰 유효 기간 (0이면 만료 없음)
+    pub tracking_token_ttl_secs: u64,
+}
+
+/// API 키에 개별 제한이 없을 때 적용할 기본 제한 (0이면 제한 없음)
+#[derive(Debug, Clone, Deserialize)]
+pub struct LimitsConfig {
+    /// API 키별 초당 요청 수
+    pub api_requests_per_sec: u32,
+    /// API 키별 하루(UTC) 수신자 수
+    pub daily_recipient_quota: i64,
+    /// API 키별 한 달(UTC) 수신자 수
+    pub monthly_recipient_quota: i64,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct SnsConfig {
+    /// SNS 메시지 서명 검증 여부 (로컬 테스트 외에는 항상 활성화)
+    pub verify_signatures: bool,
+    /// 서명 인증서를 가져올 수 있는 호스트 (`*`는 라벨 하나와 일치)
+    pub allowed_cert_hosts: Vec<String>,
+    /// 수신을 허용할 토픽 ARN (비어 있으면 모든 토픽 허용)
+    pub allowed_topic_arns: Vec<String>,
+    /// 허용 목록에 명시된 토픽의 구독 요청을 자동으로 확인
+    pub auto_confirm_subscriptions: bool,
 }
 
 impl AppConfig {
     pub fn load() -> Result<Self> {
         dotenvy::dotenv().ok();
 
+        let nats_subject =
+            std::env::var("NATS_SUBJECT").unwrap_or_else(|_| "messages.email".to_string());
+        let high_priority_share: f64 = parse_env("HIGH_PRIORITY_BATCH_SHARE", "0.5")?;
+        if !(0.0..=1.0).contains(&high_priority_share) {
+            anyhow::bail!("HIGH_PRIORITY_BATCH_SHARE must be between 0.0 and 1.0");
+        }
+
         let config = Self {
             server: ServerConfig {
                 port: parse_env("SERVER_PORT", "3000").context("Failed to parse SERVER_PORT")?,
                 host: std::env::var("SERVER_HOST")
                     .unwrap_or_else(|_| "http://localhost:3000".to_string()),
+                trusted_proxy_headers: parse_list_env("TRUSTED_PROXY_HEADERS", "")
+                    .into_iter()
+                    .map(|header| header.to_ascii_lowercase())
+                    .collect(),
+                max_attachment_bytes: parse_env("MAX_ATTACHMENT_BYTES", "10485760")?,
             },
             database: DatabaseConfig {
                 url: std::env::var("DATABASE_URL").context("DATABASE_URL must be set")?,
@@ -67,17 +191,50 @@ impl AppConfig {
                 url: std::env::var("NATS_URL")
                     .unwrap_or_else(|_| "nats://127.0.0.1:4222".to_string()),
                 stream: std::env::var("NATS_STREAM").unwrap_or_else(|_| "messages".to_string()),
-                subject: std::env::var("NATS_SUBJECT")
-                    .unwrap_or_else(|_| "messages.email".to_string()),
+                subject: nats_subject.clone(),
+                high_priority_subject: std::env::var("NATS_HIGH_PRIORITY_SUBJECT")
+                    .unwrap_or_else(|_| format!("{}.high", nats_subject)),
             },
             scheduler: SchedulerConfig {
                 batch_size: parse_env("BATCH_SIZE", "1000")
                     .context("Failed to parse BATCH_SIZE")?,
                 interval_secs: parse_env("SCHEDULER_INTERVAL", "60")
                     .context("Failed to parse SCHEDULER_INTERVAL")?,
+                processing_timeout_secs: parse_env("PROCESSING_TIMEOUT_SECS", "600")?,
+                max_recoveries: parse_env("MAX_RECOVERIES", "3")?,
+                reaper_interval_secs: parse_env("REAPER_INTERVAL", "60")?,
+                retry_max_attempts: parse_env("RETRY_MAX_ATTEMPTS", "5")?,
+                retry_base_delay_secs: parse_env("RETRY_BASE_DELAY_SECS", "30")?,
+                retry_max_delay_secs: parse_env("RETRY_MAX_DELAY_SECS", "3600")?,
+                send_rate_per_sec: parse_env("SEND_RATE_PER_SEC", "0")?,
+                publish_concurrency: parse_env::<usize>("PUBLISH_CONCURRENCY", "10")?.max(1),
+                domain_rate_limits: parse_list_env("DOMAIN_RATE_LIMITS", "")
+                    .iter()
+                    .map(|limit| limit.parse())
+                    .collect::<std::result::Result<_, String>>()
+                    .map_err(|e| anyhow::anyhow!("Failed to parse DOMAIN_RATE_LIMITS: {}", e))?,
+                high_priority_share,
             },
             security: SecurityConfig {
-                api_key: std::env::var("API_KEY").context("API_KEY must be set")?,
+                api_key: std::env::var("API_KEY").ok().filter(|key| !key.is_empty()),
+                tracking_secret: std::env::var("TRACKING_SECRET")
+                    .context("TRACKING_SECRET must be set")?,
+                tracking_previous_secrets: parse_list_env("TRACKING_PREVIOUS_SECRETS", ""),
+                tracking_token_ttl_secs: parse_env("TRACKING_TOKEN_TTL_SECS", "0")?,
+            },
+            sns: SnsConfig {
+                verify_signatures: parse_env("SNS_VERIFY_SIGNATURES", "true")?,
+                allowed_cert_hosts: parse_list_env(
+                    "SNS_ALLOWED_CERT_HOSTS",
+                    "sns.*.amazonaws.com,sns.*.amazonaws.com.cn",
+                ),
+                allowed_topic_arns: parse_list_env("SNS_ALLOWED_TOPIC_ARNS", ""),
+                auto_confirm_subscriptions: parse_env("SNS_AUTO_CONFIRM_SUBSCRIPTIONS", "false")?,
+            },
+            limits: LimitsConfig {
+                api_requests_per_sec: parse_env("API_REQUESTS_PER_SEC", "0")?,
+                daily_recipient_quota: parse_env("DAILY_RECIPIENT_QUOTA", "0")?,
+                monthly_recipient_quota: parse_env("MONTHLY_RECIPIENT_QUOTA", "0")?,
             },
         };
 
@@ -97,6 +254,17 @@ where
         .map_err(|e| anyhow::anyhow!("Failed to parse {}: {}", key, e))
 }
 
+/// 쉼표로 구분된 목록 (빈 항목 제외)
+fn parse_list_env(key: &str, default: &str) -> Vec<String> {
+    std::env::var(key)
+        .unwrap_or_else(|_| default.to_string())
+        .split(',')
+        .map(str::trim)
+        .filter(|item| !item.is_empty())
+        .map(String::from)
+        .collect()
+}
+
 pub struct Database;
 
 impl Database {
@@ -126,3 +294,57 @@ impl Database {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_domain_rate_limit() {
+        let limit: DomainRateLimit = " Outlook.com | hotmail.com=2.5".parse().unwrap();
+        assert_eq!(
+            limit,
+            DomainRateLimit {
+                name: "outlook.com|hotmail.com".to_string(),
+                domains: vec!["outlook.com".to_string(), "hotmail.com".to_string()],
+                per_sec: 2.5,
+            }
+        );
+
+        assert!("gmail.com".parse::<DomainRateLimit>().is_err());
+        assert!("gmail.com=0".parse::<DomainRateLimit>().is_err());
+        assert!("=10".parse::<DomainRateLimit>().is_err());
+    }
+
+    #[test]
+    fn test_nats_priority_subjects() {
+        let config = NatsConfig {
+            url: "nats://127.0.0.1:4222".to_string(),
+            stream: "messages".to_string(),
+            subject: "messages.email".to_string(),
+            high_priority_subject: "messages.email.high".to_string(),
+        };
+        assert_eq!(
+            config.subject_for(MessagePriority::Normal),
+            "messages.email"
+        );
+        assert_eq!(
+            config.subject_for(MessagePriority::High),
+            "messages.email.high"
+        );
+        assert_eq!(
+            config.subjects(),
+            vec![
+                "messages.email".to_string(),
+                "messages.email.high".to_string()
+            ]
+        );
+
+        // 같은 subject를 쓰도록 설정하면 스트림에는 한 번만 등록
+        let shared = NatsConfig {
+            high_priority_subject: "messages.email".to_string(),
+            ..config
+        };
+        assert_eq!(shared.subjects(), vec!["messages.email".to_string()]);
+    }
+}
@@ -1,10 +1,22 @@
+use crate::{
+    models::{
+        email::{EmailRequest, EmailResult, MessagePriority},
+        suppression::Suppression,
+        template::{EmailTemplate, EmailTemplateVersion},
+        tenant::{ApiKey, ApiScope, Tenant},
+    },
+    services::renderer,
+};
+use base64::Engine;
 use chrono::{DateTime, Utc};
 use serde::{self, de::Error, Deserializer};
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use serde_json::Value;
+use std::collections::{BTreeMap, HashMap};
+use uuid::Uuid;
 use validator::{Validate, ValidationError};
 
-#[derive(Debug, Deserialize, Validate)]
+#[derive(Debug, Deserialize, Serialize, Validate)]
 pub struct CreateMessageRequest {
     #[validate(length(min = 1, max = 100, message = "Must have between 1 and 100 messages"))]
     pub messages: Vec<MessageRequest>,
@@ -36,6 +48,7 @@ where
 }
 
 #[derive(Debug, Deserialize, Serialize, Validate)]
+#[validate(schema(function = "validate_message_source"))]
 pub struct MessageRequest {
     #[validate(length(
         min = 0,
@@ -50,22 +63,39 @@ pub struct MessageRequest {
     pub topic_id: Option<String>,
 
     #[validate(length(min = 1, max = 1000, message = "Must have between 1 and 1000 emails"))]
-    #[validate(custom = "validate_emails")]
-    pub emails: Vec<String>,
+    #[validate(custom = "validate_recipients")]
+    pub emails: Vec<Recipient>,
 
     #[validate(length(
         min = 1,
         max = 255,
         message = "Subject must be between 1 and 255 characters"
     ))]
-    pub subject: String,
+    pub subject: Option<String>,
 
     #[validate(length(
         min = 1,
         max = 65535,
         message = "Content must be between 1 and 65535 characters"
     ))]
-    pub content: String,
+    pub content: Option<String>,
+
+    /// 텍스트 본문 (생략하면 HTML 본문에서 자동 생성)
+    #[serde(default, rename = "textContent")]
+    #[validate(length(
+        min = 1,
+        max = 65535,
+        message = "Text content must be between 1 and 65535 characters"
+    ))]
+    pub text_content: Option<String>,
+
+    /// subject/content 대신 사용할 저장된 템플릿
+    #[serde(default, rename = "templateId")]
+    pub template_id: Option<i32>,
+
+    /// 생략하면 템플릿의 최신 버전 사용
+    #[serde(default, rename = "templateVersion")]
+    pub template_version: Option<i32>,
 
     #[serde(
         default,
@@ -73,37 +103,565 @@ pub struct MessageRequest {
         rename = "scheduledAt"
     )]
     pub scheduled_at: Option<DateTime<Utc>>,
+
+    /// `high`는 대량 발송보다 먼저 처리되고 별도의 NATS subject로 발행됨 (기본값 `normal`)
+    #[serde(default, skip_serializing_if = "MessagePriority::is_normal")]
+    pub priority: MessagePriority,
+
+    /// 생략하면 발송 서비스의 기본 발신자 사용
+    #[serde(default)]
+    #[validate(custom = "validate_sender")]
+    pub from: Option<Sender>,
+
+    #[serde(default, rename = "replyTo")]
+    #[validate(length(max = 10, message = "Must have at most 10 replyTo addresses"))]
+    #[validate(custom = "validate_addresses")]
+    pub reply_to: Vec<String>,
+
+    #[serde(default)]
+    #[validate(length(max = 50, message = "Must have at most 50 cc addresses"))]
+    #[validate(custom = "validate_addresses")]
+    pub cc: Vec<String>,
+
+    #[serde(default)]
+    #[validate(length(max = 50, message = "Must have at most 50 bcc addresses"))]
+    #[validate(custom = "validate_addresses")]
+    pub bcc: Vec<String>,
+
+    /// 추가 메일 헤더 (발신자/수신자 등 직접 지정하는 헤더는 사용 불가)
+    #[serde(default)]
+    #[validate(custom = "validate_headers")]
+    pub headers: BTreeMap<String, String>,
+
+    /// 메시지의 모든 수신자에게 동일하게 첨부되는 파일
+    #[serde(default)]
+    #[validate(length(max = 20, message = "Must have at most 20 attachments"))]
+    #[validate(custom = "validate_attachment_cids")]
+    #[validate]
+    pub attachments: Vec<AttachmentRequest>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct AttachmentRequest {
+    #[validate(length(
+        min = 1,
+        max = 255,
+        message = "Filename must be between 1 and 255 characters"
+    ))]
+    #[validate(custom = "validate_filename")]
+    pub filename: String,
+
+    /// 생략하면 application/octet-stream
+    #[validate(regex(
+        path = "CONTENT_TYPE_REGEX",
+        message = "Content type must be a valid MIME type"
+    ))]
+    pub content_type: Option<String>,
+
+    /// 본문에서 `cid:`로 참조할 인라인 이미지의 Content-ID (꺾쇠 괄호 제외)
+    #[validate(regex(
+        path = "CONTENT_ID_REGEX",
+        message = "Content ID must contain only alphanumeric characters and . _ - @"
+    ))]
+    pub content_id: Option<String>,
+
+    /// base64로 인코딩된 파일 내용
+    #[validate(length(min = 1, message = "Attachment content must not be empty"))]
+    pub content: String,
 }
 
+impl AttachmentRequest {
+    /// multipart로 업로드된 파일 (JSON 요청과 같은 검증을 거치도록 base64로 변환)
+    pub fn from_upload(
+        filename: String,
+        content_type: Option<String>,
+        content_id: Option<String>,
+        data: &[u8],
+    ) -> Self {
+        Self {
+            filename,
+            content_type,
+            content_id,
+            content: base64::engine::general_purpose::STANDARD.encode(data),
+        }
+    }
+
+    /// base64 내용 디코딩 (줄바꿈 등 공백 허용)
+    pub fn decode(&self) -> Result<Vec<u8>, String> {
+        let encoded: String = self
+            .content
+            .chars()
+            .filter(|c| !c.is_ascii_whitespace())
+            .collect();
+        base64::engine::general_purpose::STANDARD
+            .decode(encoded)
+            .map_err(|_| format!("Attachment content is not valid base64: {}", self.filename))
+    }
+
+    pub fn content_type(&self) -> &str {
+        self.content_type
+            .as_deref()
+            .unwrap_or("application/octet-stream")
+    }
+}
+
+/// multipart 요청에서 메시지 생성 요청 JSON을 담는 필드 이름
+pub const MULTIPART_PAYLOAD_FIELD: &str = "payload";
+
+/// 첨부 파일 필드 이름에서 메시지 인덱스 추출 (`attachments`는 첫 번째 메시지, `attachments[N]`은 N번째)
+pub fn multipart_attachment_index(field_name: &str) -> Option<usize> {
+    let rest = field_name.strip_prefix("attachments")?;
+    if rest.is_empty() {
+        return Some(0);
+    }
+    rest.strip_prefix('[')?.strip_suffix(']')?.parse().ok()
+}
+
+/// subject+content와 templateId 중 정확히 하나만 지정되었는지 확인
+fn validate_message_source(message: &MessageRequest) -> Result<(), ValidationError> {
+    let has_inline = message.subject.is_some() || message.content.is_some();
+
+    match message.template_id {
+        Some(_) if has_inline => Err(ValidationError::new(
+            "template_id_conflicts_with_subject_and_content",
+        )),
+        Some(_) => Ok(()),
+        None if message.template_version.is_some() => Err(ValidationError::new(
+            "template_version_requires_template_id",
+        )),
+        None if message.subject.is_none() || message.content.is_none() => Err(
+            ValidationError::new("subject_and_content_or_template_id_required"),
+        ),
+        None => Ok(()),
+    }
+}
+
+impl MessageRequest {
+    /// 할당량에 반영할 수신자 수 (수신자마다 발송되는 메일에 참조/숨은 참조 주소가 모두 포함됨)
+    pub fn recipient_count(&self) -> i64 {
+        (self.emails.len() * (1 + self.cc.len() + self.bcc.len())) as i64
+    }
+
+    /// 수신자별 변수로 제목과 본문(텍스트 본문 포함)의 모든 플레이스홀더를 채울 수 있는지 확인
+    pub fn validate_variables(&self, subject: &str, content: &str) -> Result<(), String> {
+        let text_content = self.text_content.as_deref().unwrap_or("");
+        for recipient in &self.emails {
+            let vars = recipient.vars();
+            let mut undefined = renderer::undefined_variables(subject, vars);
+            for template in [content, text_content] {
+                for name in renderer::undefined_variables(template, vars) {
+                    if !undefined.contains(&name) {
+                        undefined.push(name);
+                    }
+                }
+            }
+
+            if !undefined.is_empty() {
+                return Err(format!(
+                    "Undefined template variables for {}: {}",
+                    recipient.email().trim(),
+                    undefined.join(", ")
+                ));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// 수신자 (이메일 문자열 또는 개인화 변수를 포함한 객체)
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(untagged)]
+pub enum Recipient {
+    Address(String),
+    WithVars {
+        email: String,
+        #[serde(default)]
+        vars: BTreeMap<String, String>,
+    },
+}
+
+impl Recipient {
+    pub fn email(&self) -> &str {
+        match self {
+            Recipient::Address(email) => email,
+            Recipient::WithVars { email, .. } => email,
+        }
+    }
+
+    pub fn vars(&self) -> &BTreeMap<String, String> {
+        static EMPTY: BTreeMap<String, String> = BTreeMap::new();
+        match self {
+            Recipient::Address(_) => &EMPTY,
+            Recipient::WithVars { vars, .. } => vars,
+        }
+    }
+}
+
+/// 발신자 (이메일 문자열 또는 표시 이름을 포함한 객체)
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(untagged)]
+pub enum Sender {
+    Address(String),
+    WithName { email: String, name: Option<String> },
+}
+
+impl Sender {
+    pub fn email(&self) -> &str {
+        match self {
+            Sender::Address(email) => email,
+            Sender::WithName { email, .. } => email,
+        }
+    }
+
+    pub fn name(&self) -> Option<&str> {
+        match self {
+            Sender::Address(_) => None,
+            Sender::WithName { name, .. } => name.as_deref(),
+        }
+    }
+}
+
+/// 요청으로 지정할 수 없는 헤더 (별도 필드로 지정하거나 발송 시 생성됨)
+const RESERVED_HEADERS: &[&str] = &[
+    "bcc",
+    "cc",
+    "content-transfer-encoding",
+    "content-type",
+    "date",
+    "from",
+    "list-unsubscribe",
+    "list-unsubscribe-post",
+    "message-id",
+    "mime-version",
+    "reply-to",
+    "return-path",
+    "sender",
+    "subject",
+    "to",
+];
+
 lazy_static::lazy_static! {
     static ref TOPIC_ID_REGEX: regex::Regex = regex::Regex::new(r"^[a-zA-Z0-9_-]+$").unwrap();
     static ref EMAIL_REGEX: regex::Regex = regex::Regex::new(
         r"^[a-zA-Z0-9._%+-]+@[a-zA-Z0-9.-]+\.[a-zA-Z]{2,}$"
     ).unwrap();
+    static ref VARIABLE_NAME_REGEX: regex::Regex =
+        regex::Regex::new(r"^[a-zA-Z_][a-zA-Z0-9_]{0,63}$").unwrap();
+    static ref HEADER_NAME_REGEX: regex::Regex =
+        regex::Regex::new(r"^[A-Za-z0-9][A-Za-z0-9-]{0,75}$").unwrap();
+    static ref CONTENT_TYPE_REGEX: regex::Regex =
+        regex::Regex::new(r"^[A-Za-z0-9][A-Za-z0-9!#$&^_.+-]{0,63}/[A-Za-z0-9][A-Za-z0-9!#$&^_.+-]{0,126}$").unwrap();
+    static ref CONTENT_ID_REGEX: regex::Regex =
+        regex::Regex::new(r"^[A-Za-z0-9._@-]{1,255}$").unwrap();
 }
 
-fn validate_emails(emails: &[String]) -> Result<(), ValidationError> {
-    for email in emails.iter() {
-        let trimmed = email.trim();
-        if trimmed.is_empty() {
-            return Err(ValidationError::new("email_empty"));
+fn validate_recipients(recipients: &[Recipient]) -> Result<(), ValidationError> {
+    for recipient in recipients.iter() {
+        validate_email(recipient.email())?;
+
+        let vars = recipient.vars();
+        if vars.len() > 50 {
+            return Err(ValidationError::new("too_many_variables"));
         }
-        if trimmed.len() > 254 {
-            return Err(ValidationError::new("email_too_long"));
+        for (name, value) in vars {
+            if !VARIABLE_NAME_REGEX.is_match(name) {
+                return Err(ValidationError::new("variable_name_invalid"));
+            }
+            if value.len() > 1000 {
+                return Err(ValidationError::new("variable_value_too_long"));
+            }
+        }
+    }
+    Ok(())
+}
+
+fn validate_sender(sender: &Sender) -> Result<(), ValidationError> {
+    validate_email(sender.email())?;
+
+    if let Some(name) = sender.name() {
+        if name.trim().is_empty() || name.chars().count() > 100 {
+            return Err(ValidationError::new("sender_name_length"));
         }
-        if !EMAIL_REGEX.is_match(trimmed) {
-            return Err(ValidationError::new("email_invalid_format"));
+        if name.chars().any(char::is_control) {
+            return Err(ValidationError::new("sender_name_invalid"));
         }
     }
     Ok(())
 }
 
-#[derive(Debug, Serialize)]
+fn validate_addresses(addresses: &[String]) -> Result<(), ValidationError> {
+    addresses
+        .iter()
+        .try_for_each(|address| validate_email(address))
+}
+
+fn validate_headers(headers: &BTreeMap<String, String>) -> Result<(), ValidationError> {
+    if headers.len() > 20 {
+        return Err(ValidationError::new("too_many_headers"));
+    }
+    for (name, value) in headers {
+        if !HEADER_NAME_REGEX.is_match(name) {
+            return Err(ValidationError::new("header_name_invalid"));
+        }
+        if RESERVED_HEADERS.contains(&name.to_ascii_lowercase().as_str()) {
+            return Err(ValidationError::new("header_name_reserved"));
+        }
+        // 줄바꿈으로 다른 헤더를 주입하지 못하도록 제어 문자 금지
+        if value.len() > 998 || value.chars().any(char::is_control) {
+            return Err(ValidationError::new("header_value_invalid"));
+        }
+    }
+    Ok(())
+}
+
+fn validate_filename(filename: &str) -> Result<(), ValidationError> {
+    // 경로 구분자와 제어 문자(헤더 주입) 금지
+    if filename
+        .chars()
+        .any(|c| c.is_control() || c == '/' || c == '\\')
+    {
+        return Err(ValidationError::new("filename_invalid"));
+    }
+    Ok(())
+}
+
+fn validate_attachment_cids(attachments: &[AttachmentRequest]) -> Result<(), ValidationError> {
+    let mut cids = std::collections::HashSet::new();
+    for cid in attachments.iter().filter_map(|a| a.content_id.as_deref()) {
+        if !cids.insert(cid) {
+            return Err(ValidationError::new("attachment_content_id_duplicated"));
+        }
+    }
+    Ok(())
+}
+
+fn validate_email(email: &str) -> Result<(), ValidationError> {
+    let trimmed = email.trim();
+    if trimmed.is_empty() {
+        return Err(ValidationError::new("email_empty"));
+    }
+    if trimmed.len() > 254 {
+        return Err(ValidationError::new("email_too_long"));
+    }
+    if !EMAIL_REGEX.is_match(trimmed) {
+        return Err(ValidationError::new("email_invalid_format"));
+    }
+    Ok(())
+}
+
+#[derive(Debug, Serialize, Deserialize)]
 pub struct CreateMessageResponse {
     pub count: usize,
     pub elapsed: String,
 }
 
+#[derive(Debug, Serialize)]
+pub struct StopMessageResponse {
+    pub count: u64,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MessageResponse {
+    pub id: Uuid,
+    pub topic_id: String,
+    pub to_email: String,
+    pub status: String,
+    pub priority: MessagePriority,
+    pub error: Option<String>,
+    pub scheduled_at: Option<DateTime<Utc>>,
+    pub attempts: i16,
+    pub next_attempt_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl From<EmailRequest> for MessageResponse {
+    fn from(request: EmailRequest) -> Self {
+        Self {
+            id: request.id,
+            topic_id: request.topic_id,
+            to_email: request.to_email,
+            status: request.status.to_string(),
+            priority: request.priority,
+            error: request.error,
+            scheduled_at: request.scheduled_at,
+            attempts: request.attempts,
+            next_attempt_at: request.next_attempt_at,
+            created_at: request.created_at,
+            updated_at: request.updated_at,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MessageListResponse {
+    pub items: Vec<MessageResponse>,
+    pub next_cursor: Option<Uuid>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MessageDetailResponse {
+    #[serde(flatten)]
+    pub message: MessageResponse,
+    pub subject: Option<String>,
+    pub template_id: Option<i32>,
+    pub template_version: Option<i32>,
+    pub attempt_history: Value,
+    pub events: Vec<MessageEventResponse>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MessageEventResponse {
+    pub status: String,
+    pub raw: Value,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub event_at: Option<DateTime<Utc>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bounce_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bounce_sub_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub complaint_feedback_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub diagnostic: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<EmailResult> for MessageEventResponse {
+    fn from(result: EmailResult) -> Self {
+        Self {
+            status: result.status,
+            raw: result.raw,
+            event_at: result.event_at,
+            bounce_type: result.bounce_type,
+            bounce_sub_type: result.bounce_sub_type,
+            complaint_feedback_type: result.complaint_feedback_type,
+            diagnostic: result.diagnostic,
+            created_at: result.created_at,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Validate)]
+pub struct CreateTemplateRequest {
+    #[validate(length(
+        min = 1,
+        max = 100,
+        message = "Template name must be between 1 and 100 characters"
+    ))]
+    #[validate(regex(
+        path = "TOPIC_ID_REGEX",
+        message = "Template name must contain only alphanumeric characters, hyphens, and underscores"
+    ))]
+    pub name: String,
+
+    #[validate(length(max = 255, message = "Description must be at most 255 characters"))]
+    pub description: Option<String>,
+
+    #[validate(length(
+        min = 1,
+        max = 255,
+        message = "Subject must be between 1 and 255 characters"
+    ))]
+    pub subject: String,
+
+    #[validate(length(
+        min = 1,
+        max = 65535,
+        message = "Content must be between 1 and 65535 characters"
+    ))]
+    pub content: String,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+pub struct UpdateTemplateRequest {
+    #[validate(length(max = 255, message = "Description must be at most 255 characters"))]
+    pub description: Option<String>,
+
+    #[validate(length(
+        min = 1,
+        max = 255,
+        message = "Subject must be between 1 and 255 characters"
+    ))]
+    pub subject: String,
+
+    #[validate(length(
+        min = 1,
+        max = 65535,
+        message = "Content must be between 1 and 65535 characters"
+    ))]
+    pub content: String,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TemplateResponse {
+    pub id: i32,
+    pub name: String,
+    pub description: Option<String>,
+    pub latest_version: i32,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl From<EmailTemplate> for TemplateResponse {
+    fn from(template: EmailTemplate) -> Self {
+        Self {
+            id: template.id,
+            name: template.name,
+            description: template.description,
+            latest_version: template.latest_version,
+            created_at: template.created_at,
+            updated_at: template.updated_at,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TemplateVersionResponse {
+    pub template_id: i32,
+    pub version: i32,
+    pub subject: String,
+    pub content: String,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<EmailTemplateVersion> for TemplateVersionResponse {
+    fn from(version: EmailTemplateVersion) -> Self {
+        Self {
+            template_id: version.template_id,
+            version: version.version,
+            subject: version.subject,
+            content: version.content,
+            created_at: version.created_at,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TemplateDetailResponse {
+    #[serde(flatten)]
+    pub template: TemplateResponse,
+    pub latest: TemplateVersionResponse,
+}
+
+#[derive(Debug, Serialize)]
+pub struct TemplateListResponse {
+    pub items: Vec<TemplateResponse>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct TemplateVersionListResponse {
+    pub items: Vec<TemplateVersionResponse>,
+}
+
 #[derive(Debug, Serialize)]
 pub struct ResultCountResponse {
     pub request: RequestCounts,
@@ -122,6 +680,306 @@ pub struct RequestCounts {
 #[derive(Debug, Serialize)]
 pub struct ResultCounts {
     pub statuses: HashMap<String, i64>,
+    pub opens: OpenCounts,
+    pub clicks: ClickCounts,
+}
+
+/// 트래킹 픽셀 오픈 수 (이미지 프록시/사전 로드에 의한 기계 오픈 구분)
+#[derive(Debug, Default, Serialize)]
+pub struct OpenCounts {
+    pub human: i64,
+    pub machine: i64,
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct ClickCounts {
+    pub total: i64,
+    pub links: Vec<LinkClickCount>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct LinkClickCount {
+    pub index: i32,
+    pub url: Option<String>,
+    pub total: i64,
+    pub unique: i64,
+}
+
+#[derive(Debug, Deserialize, Serialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateSuppressionRequest {
+    #[validate(custom = "validate_email")]
+    pub email: String,
+
+    /// 생략하면 모든 토픽에 적용
+    #[validate(length(
+        min = 1,
+        max = 50,
+        message = "Topic ID must be between 1 and 50 characters"
+    ))]
+    #[validate(regex(
+        path = "TOPIC_ID_REGEX",
+        message = "Topic ID must contain only alphanumeric characters, hyphens, and underscores"
+    ))]
+    pub topic_id: Option<String>,
+
+    #[validate(length(
+        min = 1,
+        max = 50,
+        message = "Reason must be between 1 and 50 characters"
+    ))]
+    #[validate(regex(
+        path = "TOPIC_ID_REGEX",
+        message = "Reason must contain only alphanumeric characters, hyphens, and underscores"
+    ))]
+    pub reason: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+pub struct ImportSuppressionsRequest {
+    #[validate(length(
+        min = 1,
+        max = 10000,
+        message = "Must have between 1 and 10000 suppressions"
+    ))]
+    #[validate]
+    pub suppressions: Vec<CreateSuppressionRequest>,
+}
+
+/// 내보내기 CSV 헤더 (가져오기에서도 같은 열 순서 사용)
+pub const SUPPRESSION_CSV_HEADER: &str = "email,topic_id,reason,created_at";
+
+impl ImportSuppressionsRequest {
+    /// `email,topic_id,reason` 순서의 CSV 파싱 (헤더 행과 빈 값은 생략 가능)
+    pub fn from_csv(body: &str) -> Self {
+        let suppressions = body
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty())
+            .enumerate()
+            // 첫 행의 첫 열이 `email`이면 헤더 행으로 보고 생략
+            .filter(|(index, line)| *index > 0 || !is_csv_header(line))
+            .map(|(_, line)| {
+                let mut fields = line.split(',').map(str::trim);
+                let mut next_field = || {
+                    fields
+                        .next()
+                        .filter(|field| !field.is_empty())
+                        .map(str::to_string)
+                };
+                CreateSuppressionRequest {
+                    email: next_field().unwrap_or_default(),
+                    topic_id: next_field(),
+                    reason: next_field(),
+                }
+            })
+            .collect();
+
+        Self { suppressions }
+    }
+}
+
+fn is_csv_header(line: &str) -> bool {
+    line.split(',')
+        .next()
+        .is_some_and(|field| field.trim().eq_ignore_ascii_case("email"))
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ImportSuppressionsResponse {
+    pub imported: u64,
+    pub skipped: u64,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SuppressionResponse {
+    pub id: i32,
+    pub email: String,
+    pub topic_id: Option<String>,
+    pub reason: String,
+    pub request_id: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<Suppression> for SuppressionResponse {
+    fn from(suppression: Suppression) -> Self {
+        Self {
+            id: suppression.id,
+            email: suppression.email,
+            topic_id: suppression.topic_id,
+            reason: suppression.reason,
+            request_id: suppression.request_id,
+            created_at: suppression.created_at,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SuppressionListResponse {
+    pub items: Vec<SuppressionResponse>,
+    pub next_cursor: Option<i32>,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+pub struct CreateTenantRequest {
+    #[validate(length(
+        min = 1,
+        max = 100,
+        message = "Tenant name must be between 1 and 100 characters"
+    ))]
+    #[validate(regex(
+        path = "TOPIC_ID_REGEX",
+        message = "Tenant name must contain only alphanumeric characters, hyphens, and underscores"
+    ))]
+    pub name: String,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TenantResponse {
+    pub id: i32,
+    pub name: String,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<Tenant> for TenantResponse {
+    fn from(tenant: Tenant) -> Self {
+        Self {
+            id: tenant.id,
+            name: tenant.name,
+            created_at: tenant.created_at,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct TenantListResponse {
+    pub items: Vec<TenantResponse>,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateApiKeyRequest {
+    pub tenant_id: i32,
+
+    #[validate(length(
+        min = 1,
+        max = 100,
+        message = "API key name must be between 1 and 100 characters"
+    ))]
+    pub name: String,
+
+    #[validate(custom = "validate_scopes")]
+    pub scopes: Vec<String>,
+
+    /// 설정하면 이 접두사로 시작하는 토픽만 다룰 수 있음
+    #[validate(length(
+        min = 1,
+        max = 50,
+        message = "Topic prefix must be between 1 and 50 characters"
+    ))]
+    #[validate(regex(
+        path = "TOPIC_ID_REGEX",
+        message = "Topic prefix must contain only alphanumeric characters, hyphens, and underscores"
+    ))]
+    pub topic_prefix: Option<String>,
+
+    pub expires_at: Option<DateTime<Utc>>,
+
+    /// 생략하면 설정의 기본값, 0이면 제한 없음
+    #[validate(range(min = 0, message = "requestsPerSec must not be negative"))]
+    pub requests_per_sec: Option<i32>,
+
+    #[validate(range(min = 0, message = "dailyRecipientQuota must not be negative"))]
+    pub daily_recipient_quota: Option<i64>,
+
+    #[validate(range(min = 0, message = "monthlyRecipientQuota must not be negative"))]
+    pub monthly_recipient_quota: Option<i64>,
+}
+
+fn validate_scopes(scopes: &[String]) -> Result<(), ValidationError> {
+    if scopes.is_empty() {
+        return Err(ValidationError::new("scopes_required"));
+    }
+    if scopes
+        .iter()
+        .any(|scope| scope.parse::<ApiScope>().is_err())
+    {
+        return Err(ValidationError::new("scope_unknown"));
+    }
+    Ok(())
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ApiKeyResponse {
+    pub id: i32,
+    pub tenant_id: i32,
+    pub name: String,
+    pub key_prefix: String,
+    pub scopes: Vec<String>,
+    pub topic_prefix: Option<String>,
+    pub expires_at: Option<DateTime<Utc>>,
+    pub requests_per_sec: Option<i32>,
+    pub daily_recipient_quota: Option<i64>,
+    pub monthly_recipient_quota: Option<i64>,
+    pub last_used_at: Option<DateTime<Utc>>,
+    pub revoked_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<ApiKey> for ApiKeyResponse {
+    fn from(api_key: ApiKey) -> Self {
+        Self {
+            id: api_key.id,
+            tenant_id: api_key.tenant_id,
+            name: api_key.name,
+            key_prefix: api_key.key_prefix,
+            scopes: api_key.scopes,
+            topic_prefix: api_key.topic_prefix,
+            expires_at: api_key.expires_at,
+            requests_per_sec: api_key.requests_per_sec,
+            daily_recipient_quota: api_key.daily_recipient_quota,
+            monthly_recipient_quota: api_key.monthly_recipient_quota,
+            last_used_at: api_key.last_used_at,
+            revoked_at: api_key.revoked_at,
+            created_at: api_key.created_at,
+        }
+    }
+}
+
+/// 발급 응답 (키 원문은 이 응답에서만 확인 가능)
+#[derive(Debug, Serialize)]
+pub struct CreateApiKeyResponse {
+    #[serde(flatten)]
+    pub api_key: ApiKeyResponse,
+    pub key: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ApiKeyListResponse {
+    pub items: Vec<ApiKeyResponse>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UsagePeriodResponse {
+    pub used: i64,
+    /// None이면 제한 없음
+    pub limit: Option<i64>,
+    pub resets_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UsageResponse {
+    pub api_key_id: Option<i32>,
+    pub requests_per_sec: Option<u32>,
+    pub daily: UsagePeriodResponse,
+    pub monthly: UsagePeriodResponse,
 }
 
 #[derive(Debug, Serialize)]
@@ -129,6 +987,17 @@ pub struct SentCountResponse {
     pub count: i64,
 }
 
+#[derive(Debug, Serialize)]
+pub struct DomainSentCount {
+    pub domain: String,
+    pub count: i64,
+}
+
+#[derive(Debug, Serialize)]
+pub struct DomainSentCountResponse {
+    pub items: Vec<DomainSentCount>,
+}
+
 #[derive(Debug, Serialize)]
 pub struct HealthResponse {
     pub status: String,
@@ -144,22 +1013,252 @@ pub struct SnsMessage {
     pub message: String,
 
     #[serde(rename = "MessageId")]
-    #[allow(dead_code)]
     pub message_id: String,
 
+    #[serde(rename = "Subject")]
+    pub subject: Option<String>,
+
+    #[serde(rename = "Timestamp")]
+    pub timestamp: Option<String>,
+
+    #[serde(rename = "TopicArn")]
+    pub topic_arn: Option<String>,
+
     #[serde(rename = "SubscribeURL")]
     pub subscribe_url: Option<String>,
-}
 
-#[derive(Debug, Deserialize)]
-pub struct SesNotification {
-    #[serde(rename = "notificationType")]
-    pub notification_type: String,
+    #[serde(rename = "Token")]
+    pub token: Option<String>,
+
+    #[serde(rename = "SignatureVersion")]
+    pub signature_version: Option<String>,
 
-    pub mail: SesMailInfo,
+    #[serde(rename = "Signature")]
+    pub signature: Option<String>,
+
+    #[serde(rename = "SigningCertURL")]
+    pub signing_cert_url: Option<String>,
 }
 
-#[derive(Debug, Deserialize)]
-pub struct SesMailInfo {
-    pub tags: HashMap<String, Vec<String>>,
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_message_source() {
+        let source_error = |value: Value| -> Option<String> {
+            let mut message = serde_json::json!({ "emails": ["jane@example.com"] });
+            message
+                .as_object_mut()
+                .unwrap()
+                .extend(value.as_object().unwrap().clone());
+            let message: MessageRequest = serde_json::from_value(message).unwrap();
+            validate_message_source(&message)
+                .err()
+                .map(|error| error.code.to_string())
+        };
+
+        // 직접 입력 또는 템플릿 중 하나만 지정하면 통과
+        assert_eq!(
+            source_error(serde_json::json!({"subject": "Hi", "content": "<p>Hi</p>"})),
+            None
+        );
+        assert_eq!(
+            source_error(serde_json::json!({"templateId": 3, "templateVersion": 2})),
+            None
+        );
+
+        // 둘 다 지정
+        assert_eq!(
+            source_error(serde_json::json!({"templateId": 3, "subject": "Hi"})).as_deref(),
+            Some("template_id_conflicts_with_subject_and_content")
+        );
+        // 둘 다 없음
+        assert_eq!(
+            source_error(serde_json::json!({})).as_deref(),
+            Some("subject_and_content_or_template_id_required")
+        );
+        // 본문 없이 제목만
+        assert_eq!(
+            source_error(serde_json::json!({"subject": "Hi"})).as_deref(),
+            Some("subject_and_content_or_template_id_required")
+        );
+        // templateId 없이 templateVersion만
+        assert_eq!(
+            source_error(serde_json::json!({
+                "subject": "Hi",
+                "content": "<p>Hi</p>",
+                "templateVersion": 2
+            }))
+            .as_deref(),
+            Some("template_version_requires_template_id")
+        );
+    }
+
+    #[test]
+    fn test_message_request_sender_and_headers() {
+        let message = |extra: Value| -> MessageRequest {
+            let mut value = serde_json::json!({
+                "emails": ["jane@example.com"],
+                "subject": "Hi",
+                "content": "<p>Hi</p>",
+            });
+            value
+                .as_object_mut()
+                .unwrap()
+                .extend(extra.as_object().unwrap().clone());
+            serde_json::from_value(value).unwrap()
+        };
+
+        let valid = message(serde_json::json!({
+            "from": {"email": "news@example.com", "name": "Example News"},
+            "replyTo": ["support@example.com"],
+            "cc": ["manager@example.com"],
+            "headers": {"X-Campaign-Id": "spring-2024"},
+        }));
+        assert!(valid.validate().is_ok());
+        assert_eq!(valid.from.as_ref().unwrap().name(), Some("Example News"));
+        assert!(message(serde_json::json!({"from": "news@example.com"}))
+            .validate()
+            .is_ok());
+
+        for invalid in [
+            serde_json::json!({"from": "not-an-email"}),
+            serde_json::json!({"from": {"email": "news@example.com", "name": "Evil\r\nBcc: x"}}),
+            serde_json::json!({"bcc": ["not-an-email"]}),
+            serde_json::json!({"headers": {"Reply-To": "x@example.com"}}),
+            serde_json::json!({"headers": {"X Bad": "value"}}),
+            serde_json::json!({"headers": {"X-Injected": "a\r\nBcc: x@example.com"}}),
+        ] {
+            assert!(message(invalid.clone()).validate().is_err(), "{}", invalid);
+        }
+    }
+
+    #[test]
+    fn test_recipient_count() {
+        let message = |extra: Value| -> MessageRequest {
+            let mut value = serde_json::json!({
+                "emails": ["jane@example.com", "john@example.com", "joe@example.com"],
+                "subject": "Hi",
+                "content": "<p>Hi</p>",
+            });
+            value
+                .as_object_mut()
+                .unwrap()
+                .extend(extra.as_object().unwrap().clone());
+            serde_json::from_value(value).unwrap()
+        };
+
+        assert_eq!(message(serde_json::json!({})).recipient_count(), 3);
+        assert_eq!(
+            message(serde_json::json!({"cc": ["manager@example.com"]})).recipient_count(),
+            6
+        );
+        // 수신자 3명 × (본인 + 참조 2명 + 숨은 참조 1명)
+        assert_eq!(
+            message(serde_json::json!({
+                "cc": ["manager@example.com", "lead@example.com"],
+                "bcc": ["audit@example.com"],
+            }))
+            .recipient_count(),
+            12
+        );
+    }
+
+    #[test]
+    fn test_attachment_request() {
+        let attachment = |filename: &str, content_id: Option<&str>| AttachmentRequest {
+            filename: filename.to_string(),
+            content_type: Some("application/pdf".to_string()),
+            content_id: content_id.map(String::from),
+            content: "SGVs\nbG8=".to_string(),
+        };
+
+        // 줄바꿈이 포함된 base64도 디코딩
+        assert_eq!(attachment("a.pdf", None).decode().unwrap(), b"Hello");
+        assert!(AttachmentRequest {
+            content: "not base64!".to_string(),
+            ..attachment("a.pdf", None)
+        }
+        .decode()
+        .is_err());
+
+        assert!(attachment("invoice 2024.pdf", Some("logo@example"))
+            .validate()
+            .is_ok());
+        assert!(attachment("../etc/passwd", None).validate().is_err());
+        assert!(attachment("a.pdf", Some("<logo>")).validate().is_err());
+        assert!(validate_attachment_cids(&[
+            attachment("a.png", Some("logo")),
+            attachment("b.png", Some("logo")),
+        ])
+        .is_err());
+    }
+
+    #[test]
+    fn test_multipart_attachment_index() {
+        assert_eq!(multipart_attachment_index("attachments"), Some(0));
+        assert_eq!(multipart_attachment_index("attachments[2]"), Some(2));
+        assert_eq!(multipart_attachment_index("attachments[x]"), None);
+        assert_eq!(multipart_attachment_index("attachments2"), None);
+        assert_eq!(multipart_attachment_index("file"), None);
+
+        let attachment = AttachmentRequest::from_upload(
+            "report.csv".to_string(),
+            Some("text/csv".to_string()),
+            None,
+            b"a,b\n1,2\n",
+        );
+        assert_eq!(attachment.decode().unwrap(), b"a,b\n1,2\n");
+    }
+
+    #[test]
+    fn test_import_suppressions_from_csv() {
+        let request = ImportSuppressionsRequest::from_csv(
+            "email,topic_id,reason,created_at\n\
+             jane@example.com,,Bounce,2024-01-01T00:00:00Z\n\
+             \n\
+             john@example.com, newsletter \n\
+             emailme@example.com\n",
+        );
+
+        assert_eq!(request.suppressions.len(), 3);
+        assert_eq!(request.suppressions[0].email, "jane@example.com");
+        assert_eq!(request.suppressions[0].topic_id, None);
+        assert_eq!(request.suppressions[0].reason.as_deref(), Some("Bounce"));
+        assert_eq!(
+            request.suppressions[1].topic_id.as_deref(),
+            Some("newsletter")
+        );
+        assert_eq!(request.suppressions[1].reason, None);
+        // 헤더가 아닌 `email`로 시작하는 주소는 그대로 가져옴
+        assert_eq!(request.suppressions[2].email, "emailme@example.com");
+        assert!(request.validate().is_ok());
+
+        // 헤더 행이 없어도 첫 행부터 가져옴
+        let headerless = ImportSuppressionsRequest::from_csv("email@corp.com,,Manual");
+        assert_eq!(headerless.suppressions.len(), 1);
+        assert_eq!(headerless.suppressions[0].email, "email@corp.com");
+
+        let invalid = ImportSuppressionsRequest::from_csv("not-an-email,topic");
+        assert!(invalid.validate().is_err());
+    }
+
+    #[test]
+    fn test_create_api_key_scopes() {
+        let request = |scopes: &[&str]| CreateApiKeyRequest {
+            tenant_id: 1,
+            name: "dashboard".to_string(),
+            scopes: scopes.iter().map(|scope| scope.to_string()).collect(),
+            topic_prefix: Some("marketing-".to_string()),
+            expires_at: None,
+            requests_per_sec: None,
+            daily_recipient_quota: Some(1000),
+            monthly_recipient_quota: None,
+        };
+
+        assert!(request(&["stats:read", "messages:read"]).validate().is_ok());
+        assert!(request(&[]).validate().is_err());
+        assert!(request(&["stats:write"]).validate().is_err());
+    }
 }
@@ -1,5 +1,5 @@
 use axum::{
-    http::StatusCode,
+    http::{header, HeaderValue, StatusCode},
     response::{IntoResponse, Response},
     Json,
 };
@@ -15,14 +15,25 @@ pub enum AppError {
     Validation(String),
 
     #[error("Not found: {0}")]
-    #[allow(dead_code)]
     NotFound(String),
 
+    #[error("Conflict: {0}")]
+    Conflict(String),
+
     #[error("Unauthorized")]
     Unauthorized,
 
+    #[error("Forbidden: {0}")]
+    Forbidden(String),
+
+    #[error("Too many requests: {message}")]
+    TooManyRequests {
+        message: String,
+        /// `Retry-After` 헤더로 전달할 대기 시간(초)
+        retry_after_secs: u64,
+    },
+
     #[error("Internal server error: {0}")]
-    #[allow(dead_code)]
     Internal(String),
 
     #[error("NATS error: {0}")]
@@ -47,13 +58,25 @@ pub enum AppError {
     Semaphore(String),
 }
 
+impl AppError {
+    /// 잠시 후 다시 시도하면 성공할 수 있는 일시적인 오류인지 여부
+    pub fn is_transient(&self) -> bool {
+        matches!(self, AppError::Nats(_))
+    }
+}
+
 impl IntoResponse for AppError {
     fn into_response(self) -> Response {
         let (status, error_message, should_log) = match &self {
             AppError::Database(_e) => (StatusCode::INTERNAL_SERVER_ERROR, "Database error", true),
             AppError::Validation(message) => (StatusCode::BAD_REQUEST, message.as_str(), false),
             AppError::NotFound(message) => (StatusCode::NOT_FOUND, message.as_str(), false),
+            AppError::Conflict(message) => (StatusCode::CONFLICT, message.as_str(), false),
             AppError::Unauthorized => (StatusCode::UNAUTHORIZED, "Unauthorized", false),
+            AppError::Forbidden(message) => (StatusCode::FORBIDDEN, message.as_str(), false),
+            AppError::TooManyRequests { message, .. } => {
+                (StatusCode::TOO_MANY_REQUESTS, message.as_str(), false)
+            }
             AppError::Internal(message) => {
                 (StatusCode::INTERNAL_SERVER_ERROR, message.as_str(), true)
             }
@@ -87,7 +110,16 @@ impl IntoResponse for AppError {
             "timestamp": chrono::Utc::now(),
         }));
 
-        (status, body).into_response()
+        let mut response = (status, body).into_response();
+        if let AppError::TooManyRequests {
+            retry_after_secs, ..
+        } = &self
+        {
+            response
+                .headers_mut()
+                .insert(header::RETRY_AFTER, HeaderValue::from(*retry_after_secs));
+        }
+        response
     }
 }
 
@@ -11,7 +11,7 @@ mod models;
 mod services;
 
 use config::{AppConfig, Database};
-use services::{producer::ProducerService, scheduler::SchedulerService};
+use services::{producer::ProducerService, reaper::ReaperService, scheduler::SchedulerService};
 
 #[tokio::main]
 async fn main() -> Result<()> {
@@ -47,6 +47,9 @@ async fn main() -> Result<()> {
     // 스케줄러 서비스 생성
     let scheduler = SchedulerService::new(db.clone(), producer.clone(), config.clone());
 
+    // 멈춘 Processing 요청 복구 서비스 생성
+    let reaper = ReaperService::new(db.clone(), config.clone());
+
     // 서비스를 동시에 시작
     let scheduler_handle = tokio::spawn({
         let scheduler = scheduler.clone();
@@ -60,6 +63,18 @@ async fn main() -> Result<()> {
         }
     });
 
+    let reaper_handle = tokio::spawn({
+        let reaper = reaper.clone();
+        async move {
+            info!("🧹 Starting processing reaper service");
+            if let Err(e) = reaper.run().await {
+                error!("Reaper service failed: {:#}", e);
+            } else {
+                info!("🧹 Processing reaper service stopped gracefully");
+            }
+        }
+    });
+
     let server_handle = tokio::spawn({
         let db = db.clone();
         let config = config.clone();
@@ -84,6 +99,12 @@ async fn main() -> Result<()> {
                 Err(e) => error!("📧 Scheduler service panicked: {}", e),
             }
         }
+        result = reaper_handle => {
+            match result {
+                Ok(_) => info!("🧹 Reaper service completed"),
+                Err(e) => error!("🧹 Reaper service panicked: {}", e),
+            }
+        }
         result = server_handle => {
             match result {
                 Ok(_) => info!("🌐 HTTP server completed"),
@@ -0,0 +1,15 @@
+use serde::{Deserialize, Serialize};
+
+/// 첨부 파일 다운로드 토큰 용도
+pub const ATTACHMENT_TOKEN_PURPOSE: &str = "attachment";
+
+/// 발송 시 참조하는 첨부 파일 정보 (본문 데이터 제외)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AttachmentInfo {
+    pub id: i32,
+    pub filename: String,
+    pub content_type: String,
+    /// 본문에서 `cid:`로 참조하는 인라인 이미지의 Content-ID
+    pub cid: Option<String>,
+    pub size: i32,
+}
@@ -1,7 +1,14 @@
+use crate::models::attachment::{AttachmentInfo, ATTACHMENT_TOKEN_PURPOSE};
+use crate::services::{
+    plaintext,
+    renderer::{self, Escape},
+    token::TokenSigner,
+    tracking,
+};
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use sqlx::FromRow;
-use std::fmt;
+use std::{collections::BTreeMap, fmt, str::FromStr};
 use uuid::Uuid;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, sqlx::Type, Serialize, Deserialize)]
@@ -26,7 +33,30 @@ impl fmt::Display for EmailStatus {
     }
 }
 
+impl FromStr for EmailStatus {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "created" => Ok(EmailStatus::Created),
+            "processing" => Ok(EmailStatus::Processing),
+            "sent" => Ok(EmailStatus::Sent),
+            "failed" => Ok(EmailStatus::Failed),
+            "stopped" => Ok(EmailStatus::Stopped),
+            _ => Err(format!("Unknown email status: {}", s)),
+        }
+    }
+}
+
 impl EmailStatus {
+    pub const ALL: [EmailStatus; 5] = [
+        EmailStatus::Created,
+        EmailStatus::Processing,
+        EmailStatus::Sent,
+        EmailStatus::Failed,
+        EmailStatus::Stopped,
+    ];
+
     #[allow(dead_code)]
     pub fn is_terminal(&self) -> bool {
         matches!(
@@ -35,7 +65,6 @@ impl EmailStatus {
         )
     }
 
-    #[allow(dead_code)]
     pub fn can_transition_to(&self, new_status: EmailStatus) -> bool {
         use EmailStatus::*;
         matches!(
@@ -43,13 +72,47 @@ impl EmailStatus {
             (Created, Processing) | (Processing, Sent | Failed) | (Created | Processing, Stopped)
         )
     }
+
+    /// `target` 상태로 전이할 수 있는 상태 목록 (DB 저장 값)
+    pub fn sources_of(target: EmailStatus) -> Vec<i16> {
+        Self::ALL
+            .iter()
+            .filter(|status| status.can_transition_to(target))
+            .map(|status| *status as i16)
+            .collect()
+    }
+}
+
+/// 발송 우선순위 (트랜잭션 메일은 `High`, 대량 발송은 `Normal`)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, sqlx::Type, Serialize, Deserialize)]
+#[repr(i16)]
+#[serde(rename_all = "lowercase")]
+pub enum MessagePriority {
+    #[default]
+    Normal = 0,
+    High = 1,
+}
+
+impl MessagePriority {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            MessagePriority::Normal => "normal",
+            MessagePriority::High => "high",
+        }
+    }
+
+    pub fn is_normal(&self) -> bool {
+        *self == MessagePriority::Normal
+    }
 }
 
 #[derive(Debug, Clone, FromRow, Serialize, Deserialize)]
 pub struct EmailContent {
     pub id: i32,
-    pub subject: String,
-    pub content: String,
+    /// 템플릿을 사용하는 경우 None (템플릿 버전의 제목/본문 사용)
+    pub subject: Option<String>,
+    pub content: Option<String>,
+    pub template_version_id: Option<i32>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
@@ -60,8 +123,9 @@ impl EmailContent {
         let now = Utc::now();
         Self {
             id: 0, // 데이터베이스에서 설정됨
-            subject: subject.trim().to_string(),
-            content: content.trim().to_string(),
+            subject: Some(subject.trim().to_string()),
+            content: Some(content.trim().to_string()),
+            template_version_id: None,
             created_at: now,
             updated_at: now,
         }
@@ -76,7 +140,11 @@ pub struct EmailRequest {
     pub content_id: i32,
     pub scheduled_at: Option<DateTime<Utc>>,
     pub status: EmailStatus,
+    pub priority: MessagePriority,
     pub error: Option<String>,
+    pub attempts: i16,
+    pub next_attempt_at: Option<DateTime<Utc>>,
+    pub attempt_history: serde_json::Value,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
@@ -97,7 +165,11 @@ impl EmailRequest {
             content_id,
             scheduled_at,
             status: EmailStatus::Created,
+            priority: MessagePriority::Normal,
             error: None,
+            attempts: 0,
+            next_attempt_at: None,
+            attempt_history: serde_json::Value::Array(Vec::new()),
             created_at: now,
             updated_at: now,
         }
@@ -114,6 +186,7 @@ impl EmailRequest {
     pub fn is_ready_to_send(&self, now: DateTime<Utc>) -> bool {
         self.status == EmailStatus::Created
             && self.scheduled_at.is_none_or(|scheduled| scheduled <= now)
+            && self.next_attempt_at.is_none_or(|next| next <= now)
     }
 }
 
@@ -123,6 +196,12 @@ pub struct EmailResult {
     pub request_id: Uuid,
     pub status: String,
     pub raw: serde_json::Value,
+    pub ses_message_id: Option<String>,
+    pub event_at: Option<DateTime<Utc>>,
+    pub bounce_type: Option<String>,
+    pub bounce_sub_type: Option<String>,
+    pub complaint_feedback_type: Option<String>,
+    pub diagnostic: Option<String>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
@@ -136,12 +215,27 @@ impl EmailResult {
             request_id,
             status,
             raw,
+            ses_message_id: None,
+            event_at: None,
+            bounce_type: None,
+            bounce_sub_type: None,
+            complaint_feedback_type: None,
+            diagnostic: None,
             created_at: now,
             updated_at: now,
         }
     }
 }
 
+/// 트래킹 토큰 용도 (토큰이 다른 종류의 링크에 재사용되지 않도록 서명에 포함)
+pub const OPEN_TOKEN_PURPOSE: &str = "open";
+pub const CLICK_TOKEN_PURPOSE: &str = "click";
+pub const UNSUBSCRIBE_TOKEN_PURPOSE: &str = "unsubscribe";
+
+pub fn click_token_subject(request_id: Uuid, link_index: usize) -> String {
+    format!("{}:{}", request_id, link_index)
+}
+
 #[derive(Debug, Clone, FromRow, Serialize, Deserialize)]
 pub struct EmailRequestWithContent {
     pub id: Uuid,
@@ -150,32 +244,194 @@ pub struct EmailRequestWithContent {
     pub content_id: i32,
     pub scheduled_at: Option<DateTime<Utc>>,
     pub status: EmailStatus,
+    pub priority: MessagePriority,
     pub error: Option<String>,
+    pub attempts: i16,
+    pub vars: Option<serde_json::Value>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
     pub subject: Option<String>,
     pub content: Option<String>,
+    pub text_content: Option<String>,
+    pub from_email: Option<String>,
+    pub from_name: Option<String>,
+    pub reply_to: Vec<String>,
+    pub cc: Vec<String>,
+    pub bcc: Vec<String>,
+    /// 요청에 지정된 추가 헤더 (JSON 객체)
+    pub headers: serde_json::Value,
+    /// 첨부 파일 정보 목록 (JSON 배열)
+    pub attachments: serde_json::Value,
 }
 
 impl EmailRequestWithContent {
-    pub fn generate_tracking_pixel(&self, server_host: &str) -> String {
+    /// 수신자별 개인화 변수 (저장된 값이 없으면 빈 맵)
+    pub fn variables(&self) -> BTreeMap<String, String> {
+        self.vars
+            .as_ref()
+            .and_then(|vars| serde_json::from_value(vars.clone()).ok())
+            .unwrap_or_default()
+    }
+
+    pub fn rendered_subject(&self) -> String {
+        let subject = self.subject.as_deref().unwrap_or("");
+        renderer::render(subject, &self.variables(), Escape::Header)
+    }
+
+    pub fn rendered_content(&self) -> String {
+        let content = self.content.as_deref().unwrap_or("");
+        renderer::render(content, &self.variables(), Escape::Html)
+    }
+
+    /// 텍스트 본문 (지정되지 않았으면 렌더링된 HTML 본문에서 생성, 트래킹 픽셀 제외)
+    pub fn rendered_text(&self) -> String {
+        match self.text_content.as_deref() {
+            Some(text) => renderer::render(text, &self.variables(), Escape::Text),
+            None => plaintext::html_to_text(&self.rendered_content()),
+        }
+    }
+
+    pub fn generate_tracking_pixel(&self, server_host: &str, signer: &TokenSigner) -> String {
+        let token = signer.sign(OPEN_TOKEN_PURPOSE, &self.id.to_string());
         format!(
-            r#"<img src="{}/v1/events/open?requestId={}" width="1" height="1" style="display:none;" alt="">"#,
-            server_host, self.id
+            r#"<img src="{}/v1/events/open?requestId={}&amp;token={}" width="1" height="1" style="display:none;" alt="">"#,
+            server_host, self.id, token
         )
     }
 
-    pub fn content_with_tracking(&self, server_host: &str) -> String {
-        let content = self.content.as_deref().unwrap_or("");
-        format!("{}{}", content, self.generate_tracking_pixel(server_host))
+    /// 클릭 추적 대상 링크 목록 (인덱스는 클릭 URL의 `link` 값)
+    pub fn tracked_links(&self) -> Vec<String> {
+        tracking::tracked_links(&self.rendered_content())
+    }
+
+    pub fn generate_click_url(
+        &self,
+        server_host: &str,
+        link_index: usize,
+        signer: &TokenSigner,
+    ) -> String {
+        let token = signer.sign(
+            CLICK_TOKEN_PURPOSE,
+            &click_token_subject(self.id, link_index),
+        );
+        format!(
+            "{}/v1/events/click?requestId={}&link={}&token={}",
+            server_host, self.id, link_index, token
+        )
+    }
+
+    /// 수신 거부 링크 (메일을 받은 이후 언제든 동작해야 하므로 만료 없음)
+    pub fn generate_unsubscribe_url(&self, server_host: &str, signer: &TokenSigner) -> String {
+        let token = signer.sign_without_expiry(UNSUBSCRIBE_TOKEN_PURPOSE, &self.id.to_string());
+        format!(
+            "{}/v1/unsubscribe?requestId={}&token={}",
+            server_host, self.id, token
+        )
+    }
+
+    pub fn attachment_infos(&self) -> Vec<AttachmentInfo> {
+        serde_json::from_value(self.attachments.clone()).unwrap_or_default()
+    }
+
+    /// 발송기가 첨부 파일을 내려받는 서명된 주소
+    pub fn generate_attachment_url(
+        &self,
+        server_host: &str,
+        attachment_id: i32,
+        signer: &TokenSigner,
+    ) -> String {
+        let token = signer.sign(ATTACHMENT_TOKEN_PURPOSE, &attachment_id.to_string());
+        format!(
+            "{}/v1/attachments/{}?token={}",
+            server_host, attachment_id, token
+        )
+    }
+
+    /// 발송 시 추가할 헤더 (요청 헤더와 수신 거부 헤더)
+    pub fn mail_headers(
+        &self,
+        server_host: &str,
+        signer: &TokenSigner,
+    ) -> BTreeMap<String, String> {
+        let mut headers: BTreeMap<String, String> =
+            serde_json::from_value(self.headers.clone()).unwrap_or_default();
+        headers.extend(self.list_unsubscribe_headers(server_host, signer));
+        headers
+    }
+
+    /// 원클릭 수신 거부 헤더 (RFC 2369, RFC 8058)
+    pub fn list_unsubscribe_headers(
+        &self,
+        server_host: &str,
+        signer: &TokenSigner,
+    ) -> BTreeMap<String, String> {
+        BTreeMap::from([
+            (
+                "List-Unsubscribe".to_string(),
+                format!("<{}>", self.generate_unsubscribe_url(server_host, signer)),
+            ),
+            (
+                "List-Unsubscribe-Post".to_string(),
+                "List-Unsubscribe=One-Click".to_string(),
+            ),
+        ])
+    }
+
+    pub fn content_with_tracking(&self, server_host: &str, signer: &TokenSigner) -> String {
+        let content = tracking::rewrite_links(&self.rendered_content(), |index, _| {
+            self.generate_click_url(server_host, index, signer)
+        });
+        format!(
+            "{}{}",
+            content,
+            self.generate_tracking_pixel(server_host, signer)
+        )
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::config::SecurityConfig;
     use chrono::{Duration, Utc};
 
+    fn test_signer() -> TokenSigner {
+        TokenSigner::new(&SecurityConfig {
+            api_key: None,
+            tracking_secret: "test-secret".to_string(),
+            tracking_previous_secrets: Vec::new(),
+            tracking_token_ttl_secs: 0,
+        })
+    }
+
+    /// 테스트용 기본 요청 (각 테스트는 필요한 필드만 덮어씀)
+    fn test_request() -> EmailRequestWithContent {
+        EmailRequestWithContent {
+            id: uuid::Uuid::parse_str("123e4567-e89b-12d3-a456-426614174000").unwrap(),
+            topic_id: "test-topic".to_string(),
+            to_email: "test@example.com".to_string(),
+            content_id: 1,
+            scheduled_at: None,
+            status: EmailStatus::Created,
+            priority: MessagePriority::Normal,
+            error: None,
+            attempts: 0,
+            vars: None,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+            subject: Some("Test Subject".to_string()),
+            content: Some("Test Content".to_string()),
+            text_content: None,
+            from_email: None,
+            from_name: None,
+            reply_to: Vec::new(),
+            cc: Vec::new(),
+            bcc: Vec::new(),
+            headers: serde_json::json!({}),
+            attachments: serde_json::json!([]),
+        }
+    }
+
     #[test]
     fn test_email_status_display() {
         // EmailStatus의 Display trait 구현 테스트
@@ -186,6 +442,16 @@ mod tests {
         assert_eq!(EmailStatus::Stopped.to_string(), "stopped");
     }
 
+    #[test]
+    fn test_email_status_from_str() {
+        // Display 값과 동일한 문자열을 대소문자 구분 없이 파싱
+        for status in EmailStatus::ALL {
+            assert_eq!(status.to_string().parse::<EmailStatus>(), Ok(status));
+        }
+        assert_eq!("SENT".parse::<EmailStatus>(), Ok(EmailStatus::Sent));
+        assert!("delivered".parse::<EmailStatus>().is_err());
+    }
+
     #[test]
     fn test_email_status_transitions() {
         // EmailStatus의 상태 전이 가능 여부 테스트
@@ -210,6 +476,19 @@ mod tests {
         assert!(!EmailStatus::Processing.is_terminal());
     }
 
+    #[test]
+    fn test_email_status_sources_of() {
+        // 중지 가능한 상태는 Created, Processing 뿐이어야 함
+        assert_eq!(
+            EmailStatus::sources_of(EmailStatus::Stopped),
+            vec![EmailStatus::Created as i16, EmailStatus::Processing as i16]
+        );
+        assert_eq!(
+            EmailStatus::sources_of(EmailStatus::Sent),
+            vec![EmailStatus::Processing as i16]
+        );
+    }
+
     #[test]
     fn test_email_request_creation() {
         // EmailRequest 생성 테스트
@@ -296,27 +575,26 @@ mod tests {
         );
         request_processing.update_status(EmailStatus::Processing, None);
         assert!(!request_processing.is_ready_to_send(now));
+
+        // 재시도 대기 중인 요청 - 다음 시도 시각 이전에는 발송 불가능해야 함
+        let mut request_retrying = EmailRequest::new(
+            "test-topic".to_string(),
+            "test@example.com".to_string(),
+            1,
+            None,
+        );
+        request_retrying.next_attempt_at = Some(now + Duration::minutes(5));
+        assert!(!request_retrying.is_ready_to_send(now));
+        assert!(request_retrying.is_ready_to_send(now + Duration::minutes(5)));
     }
 
     #[test]
     fn test_email_request_with_content_tracking_pixel() {
         // EmailRequestWithContent의 추적 픽셀 생성 테스트
-        let request = EmailRequestWithContent {
-            id: uuid::Uuid::parse_str("123e4567-e89b-12d3-a456-426614174000").unwrap(),
-            topic_id: "test-topic".to_string(),
-            to_email: "test@example.com".to_string(),
-            content_id: 1,
-            scheduled_at: None,
-            status: EmailStatus::Created,
-            error: None,
-            created_at: Utc::now(),
-            updated_at: Utc::now(),
-            subject: Some("Test Subject".to_string()),
-            content: Some("Test Content".to_string()),
-        };
+        let request = test_request();
 
         let server_host = "http://localhost:3000";
-        let tracking_pixel = request.generate_tracking_pixel(server_host);
+        let tracking_pixel = request.generate_tracking_pixel(server_host, &test_signer());
 
         // 추적 픽셀의 형식이 올바른지 확인
         assert!(tracking_pixel
@@ -324,27 +602,29 @@ mod tests {
         assert!(tracking_pixel.contains("123e4567-e89b-12d3-a456-426614174000"));
         assert!(tracking_pixel
             .ends_with("\" width=\"1\" height=\"1\" style=\"display:none;\" alt=\"\">"));
+
+        // 픽셀에 포함된 토큰은 해당 요청의 오픈 용도로만 검증되어야 함
+        let token = tracking_pixel
+            .split("&amp;token=")
+            .nth(1)
+            .and_then(|rest| rest.split('"').next())
+            .unwrap();
+        let signer = test_signer();
+        assert!(signer
+            .verify(OPEN_TOKEN_PURPOSE, &request.id.to_string(), token)
+            .is_ok());
+        assert!(signer
+            .verify(CLICK_TOKEN_PURPOSE, &request.id.to_string(), token)
+            .is_err());
     }
 
     #[test]
     fn test_email_request_with_content_tracking() {
         // EmailRequestWithContent의 추적 픽셀 포함 내용 생성 테스트
-        let request = EmailRequestWithContent {
-            id: uuid::Uuid::parse_str("123e4567-e89b-12d3-a456-426614174000").unwrap(),
-            topic_id: "test-topic".to_string(),
-            to_email: "test@example.com".to_string(),
-            content_id: 1,
-            scheduled_at: None,
-            status: EmailStatus::Created,
-            error: None,
-            created_at: Utc::now(),
-            updated_at: Utc::now(),
-            subject: Some("Test Subject".to_string()),
-            content: Some("Test Content".to_string()),
-        };
+        let request = test_request();
 
         let server_host = "http://localhost:3000";
-        let content_with_tracking = request.content_with_tracking(server_host);
+        let content_with_tracking = request.content_with_tracking(server_host, &test_signer());
 
         // 원본 내용과 추적 픽셀이 모두 포함되어 있는지 확인
         assert!(content_with_tracking.starts_with("Test Content"));
@@ -357,21 +637,12 @@ mod tests {
     fn test_email_request_with_content_tracking_empty_content() {
         // EmailRequestWithContent의 추적 픽셀 포함 내용 생성 테스트 (빈 내용)
         let request = EmailRequestWithContent {
-            id: uuid::Uuid::parse_str("123e4567-e89b-12d3-a456-426614174000").unwrap(),
-            topic_id: "test-topic".to_string(),
-            to_email: "test@example.com".to_string(),
-            content_id: 1,
-            scheduled_at: None,
-            status: EmailStatus::Created,
-            error: None,
-            created_at: Utc::now(),
-            updated_at: Utc::now(),
-            subject: Some("Test Subject".to_string()),
             content: Some("".to_string()),
+            ..test_request()
         };
 
         let server_host = "http://localhost:3000";
-        let content_with_tracking = request.content_with_tracking(server_host);
+        let content_with_tracking = request.content_with_tracking(server_host, &test_signer());
 
         // 빈 내용에 추적 픽셀만 추가되는지 확인
         assert!(content_with_tracking
@@ -383,25 +654,113 @@ mod tests {
     fn test_email_request_with_content_tracking_none_content() {
         // EmailRequestWithContent의 추적 픽셀 포함 내용 생성 테스트 (내용이 None)
         let request = EmailRequestWithContent {
-            id: uuid::Uuid::parse_str("123e4567-e89b-12d3-a456-426614174000").unwrap(),
-            topic_id: "test-topic".to_string(),
-            to_email: "test@example.com".to_string(),
-            content_id: 1,
-            scheduled_at: None,
-            status: EmailStatus::Created,
-            error: None,
-            created_at: Utc::now(),
-            updated_at: Utc::now(),
-            subject: Some("Test Subject".to_string()),
             content: None,
+            ..test_request()
         };
 
         let server_host = "http://localhost:3000";
-        let content_with_tracking = request.content_with_tracking(server_host);
+        let content_with_tracking = request.content_with_tracking(server_host, &test_signer());
 
         // 내용이 없을 때 추적 픽셀만 생성되는지 확인
         assert!(content_with_tracking
             .starts_with("<img src=\"http://localhost:3000/v1/events/open?requestId="));
         assert!(content_with_tracking.contains("123e4567-e89b-12d3-a456-426614174000"));
     }
+
+    #[test]
+    fn test_email_request_with_content_personalization() {
+        // 수신자 변수로 제목과 본문을 렌더링하고 본문 값은 HTML 이스케이프
+        let request = EmailRequestWithContent {
+            vars: Some(serde_json::json!({ "name": "<Lee>" })),
+            subject: Some("Hi {{ name }}".to_string()),
+            content: Some(r#"<p>{{ name }} / {{ plan | default: "free" }}</p>"#.to_string()),
+            ..test_request()
+        };
+
+        assert_eq!(request.rendered_subject(), "Hi <Lee>");
+        let content_with_tracking =
+            request.content_with_tracking("http://localhost:3000", &test_signer());
+        assert!(content_with_tracking.starts_with("<p>&lt;Lee&gt; / free</p>"));
+    }
+
+    #[test]
+    fn test_email_request_with_content_text_part() {
+        // 텍스트 본문이 없으면 HTML에서 생성하고, 있으면 이스케이프 없이 렌더링
+        let mut request = EmailRequestWithContent {
+            vars: Some(serde_json::json!({ "name": "Lee & Kim" })),
+            subject: Some("Hi".to_string()),
+            content: Some(
+                r#"<p>Hi {{ name }}, <a href="https://example.com">visit</a></p>"#.to_string(),
+            ),
+            ..test_request()
+        };
+
+        assert_eq!(
+            request.rendered_text(),
+            "Hi Lee & Kim, visit [1]\n\n[1] https://example.com"
+        );
+        assert!(!request.rendered_text().contains("/v1/events/open"));
+
+        request.text_content = Some("Hi {{ name }}".to_string());
+        assert_eq!(request.rendered_text(), "Hi Lee & Kim");
+    }
+
+    #[test]
+    fn test_email_request_with_content_click_tracking() {
+        // 본문 링크는 클릭 추적 URL로 교체되고, 인덱스로 원래 주소를 찾을 수 있어야 함
+        let request = EmailRequestWithContent {
+            vars: Some(serde_json::json!({ "code": "A1" })),
+            content: Some(
+                r#"<a href="https://example.com/promo?code={{ code }}">Promo</a>"#.to_string(),
+            ),
+            ..test_request()
+        };
+
+        let content_with_tracking =
+            request.content_with_tracking("http://localhost:3000", &test_signer());
+        assert!(content_with_tracking.starts_with(
+            r#"<a href="http://localhost:3000/v1/events/click?requestId=123e4567-e89b-12d3-a456-426614174000&amp;link=0&amp;token="#
+        ));
+        assert_eq!(
+            request.tracked_links(),
+            vec!["https://example.com/promo?code=A1".to_string()]
+        );
+        // 텍스트 본문에는 원래 주소 유지
+        assert!(request
+            .rendered_text()
+            .contains("https://example.com/promo?code=A1"));
+    }
+
+    #[test]
+    fn test_email_request_with_content_list_unsubscribe_headers() {
+        // 수신 거부 헤더에는 서명된 수신 거부 URL과 원클릭 지시자가 포함되어야 함
+        let request = EmailRequestWithContent {
+            content: Some("<p>Hello</p>".to_string()),
+            ..test_request()
+        };
+
+        let signer = test_signer();
+        let headers = request.list_unsubscribe_headers("http://localhost:3000", &signer);
+        assert_eq!(
+            headers["List-Unsubscribe-Post"],
+            "List-Unsubscribe=One-Click"
+        );
+
+        let list_unsubscribe = &headers["List-Unsubscribe"];
+        let prefix = "<http://localhost:3000/v1/unsubscribe?requestId=123e4567-e89b-12d3-a456-426614174000&token=";
+        assert!(list_unsubscribe.starts_with(prefix));
+        let token = list_unsubscribe[prefix.len()..].trim_end_matches('>');
+        assert!(signer
+            .verify(UNSUBSCRIBE_TOKEN_PURPOSE, &request.id.to_string(), token)
+            .is_ok());
+
+        // 요청 헤더와 함께 발송 헤더로 합쳐짐
+        let request = EmailRequestWithContent {
+            headers: serde_json::json!({ "X-Campaign-Id": "spring" }),
+            ..request
+        };
+        let headers = request.mail_headers("http://localhost:3000", &signer);
+        assert_eq!(headers["X-Campaign-Id"], "spring");
+        assert!(headers.contains_key("List-Unsubscribe"));
+    }
 }
@@ -1 +1,7 @@
+pub mod attachment;
 pub mod email;
+pub mod ses;
+pub mod suppression;
+pub mod template;
+pub mod tenant;
+pub mod usage;
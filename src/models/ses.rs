@@ -0,0 +1,359 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::{collections::HashMap, fmt};
+
+/// SES 이벤트 종류
+///
+/// 자격 증명 알림(`notificationType`)과 구성 세트 이벤트 게시(`eventType`) 형식을 모두 지원합니다.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SesEventType {
+    Bounce,
+    Complaint,
+    Delivery,
+    Send,
+    Reject,
+    Open,
+    Click,
+    #[serde(rename = "Rendering Failure", alias = "RenderingFailure")]
+    RenderingFailure,
+    DeliveryDelay,
+    Subscription,
+}
+
+impl SesEventType {
+    /// email_results.status에 저장되는 값
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SesEventType::Bounce => "Bounce",
+            SesEventType::Complaint => "Complaint",
+            SesEventType::Delivery => "Delivery",
+            SesEventType::Send => "Send",
+            SesEventType::Reject => "Reject",
+            SesEventType::Open => "Open",
+            SesEventType::Click => "Click",
+            SesEventType::RenderingFailure => "RenderingFailure",
+            SesEventType::DeliveryDelay => "DeliveryDelay",
+            SesEventType::Subscription => "Subscription",
+        }
+    }
+}
+
+impl fmt::Display for SesEventType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// SNS 메시지 본문으로 전달되는 SES 이벤트
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SesEvent {
+    #[serde(alias = "notificationType")]
+    pub event_type: SesEventType,
+    pub mail: SesMail,
+    pub bounce: Option<SesBounce>,
+    pub complaint: Option<SesComplaint>,
+    pub delivery: Option<SesDelivery>,
+    pub reject: Option<SesReject>,
+    pub delivery_delay: Option<SesDeliveryDelay>,
+    /// Rendering Failure 이벤트의 상세 정보
+    pub failure: Option<SesRenderingFailure>,
+    pub open: Option<SesOpen>,
+    pub click: Option<SesClick>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SesMail {
+    pub timestamp: Option<DateTime<Utc>>,
+    pub message_id: Option<String>,
+    pub source: Option<String>,
+    #[serde(default)]
+    pub destination: Vec<String>,
+    #[serde(default)]
+    pub tags: HashMap<String, Vec<String>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SesBounce {
+    /// Permanent, Transient, Undetermined
+    pub bounce_type: String,
+    pub bounce_sub_type: Option<String>,
+    #[serde(default)]
+    pub bounced_recipients: Vec<SesRecipient>,
+    pub timestamp: Option<DateTime<Utc>>,
+    pub feedback_id: Option<String>,
+    #[serde(rename = "reportingMTA")]
+    pub reporting_mta: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SesRecipient {
+    pub email_address: String,
+    pub action: Option<String>,
+    pub status: Option<String>,
+    pub diagnostic_code: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SesComplaint {
+    #[serde(default)]
+    pub complained_recipients: Vec<SesRecipient>,
+    pub timestamp: Option<DateTime<Utc>>,
+    pub feedback_id: Option<String>,
+    /// abuse, fraud, not-spam 등 (피드백 보고서가 없으면 생략됨)
+    pub complaint_feedback_type: Option<String>,
+    pub complaint_sub_type: Option<String>,
+    pub user_agent: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SesDelivery {
+    pub timestamp: Option<DateTime<Utc>>,
+    pub processing_time_millis: Option<i64>,
+    #[serde(default)]
+    pub recipients: Vec<String>,
+    pub smtp_response: Option<String>,
+    #[serde(rename = "reportingMTA")]
+    pub reporting_mta: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SesReject {
+    pub reason: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SesDeliveryDelay {
+    pub timestamp: Option<DateTime<Utc>>,
+    pub delay_type: Option<String>,
+    pub expiration_time: Option<DateTime<Utc>>,
+    #[serde(default)]
+    pub delayed_recipients: Vec<SesRecipient>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SesRenderingFailure {
+    pub error_message: Option<String>,
+    pub template_name: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SesOpen {
+    pub timestamp: Option<DateTime<Utc>>,
+    pub user_agent: Option<String>,
+    pub ip_address: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SesClick {
+    pub timestamp: Option<DateTime<Utc>>,
+    pub user_agent: Option<String>,
+    pub ip_address: Option<String>,
+    pub link: Option<String>,
+}
+
+impl SesEvent {
+    /// 발송 시 태그로 전달한 요청 ID
+    pub fn request_id(&self) -> Option<&str> {
+        self.mail
+            .tags
+            .get("request_id")
+            .and_then(|tags| tags.first())
+            .map(String::as_str)
+    }
+
+    /// 이벤트 발생 시각 (이벤트별 시각이 없으면 메일 발송 시각)
+    pub fn occurred_at(&self) -> Option<DateTime<Utc>> {
+        let event_at = match self.event_type {
+            SesEventType::Bounce => self.bounce.as_ref().and_then(|b| b.timestamp),
+            SesEventType::Complaint => self.complaint.as_ref().and_then(|c| c.timestamp),
+            SesEventType::Delivery => self.delivery.as_ref().and_then(|d| d.timestamp),
+            SesEventType::DeliveryDelay => self.delivery_delay.as_ref().and_then(|d| d.timestamp),
+            SesEventType::Open => self.open.as_ref().and_then(|o| o.timestamp),
+            SesEventType::Click => self.click.as_ref().and_then(|c| c.timestamp),
+            _ => None,
+        };
+        event_at.or(self.mail.timestamp)
+    }
+
+    /// 이벤트 세부 유형 (반송 유형/하위 유형, 수신 거부 피드백 유형)
+    pub fn bounce_type(&self) -> Option<&str> {
+        self.bounce.as_ref().map(|b| b.bounce_type.as_str())
+    }
+
+    pub fn bounce_sub_type(&self) -> Option<&str> {
+        self.bounce
+            .as_ref()
+            .and_then(|b| b.bounce_sub_type.as_deref())
+    }
+
+    pub fn complaint_feedback_type(&self) -> Option<&str> {
+        self.complaint
+            .as_ref()
+            .and_then(|c| c.complaint_feedback_type.as_deref())
+    }
+
+    /// 이후 발송을 중단해야 하는 수신자 (영구 반송, 수신 거부)
+    pub fn suppressed_recipients(&self) -> Vec<&str> {
+        let recipients = match self.event_type {
+            SesEventType::Bounce => self
+                .bounce
+                .as_ref()
+                .filter(|b| b.bounce_type == "Permanent")
+                .map(|b| b.bounced_recipients.as_slice()),
+            SesEventType::Complaint => self
+                .complaint
+                .as_ref()
+                .map(|c| c.complained_recipients.as_slice()),
+            _ => None,
+        };
+
+        recipients
+            .unwrap_or_default()
+            .iter()
+            .map(|r| r.email_address.as_str())
+            .collect()
+    }
+
+    /// 원인 파악용 진단 메시지 (진단 코드, SMTP 응답, 거부 사유, 렌더링 오류)
+    pub fn diagnostic(&self) -> Option<&str> {
+        match self.event_type {
+            SesEventType::Bounce => self
+                .bounce
+                .as_ref()
+                .and_then(|b| first_diagnostic(&b.bounced_recipients)),
+            SesEventType::Delivery => self
+                .delivery
+                .as_ref()
+                .and_then(|d| d.smtp_response.as_deref()),
+            SesEventType::Reject => self.reject.as_ref().and_then(|r| r.reason.as_deref()),
+            SesEventType::DeliveryDelay => self
+                .delivery_delay
+                .as_ref()
+                .and_then(|d| first_diagnostic(&d.delayed_recipients)),
+            SesEventType::RenderingFailure => self
+                .failure
+                .as_ref()
+                .and_then(|f| f.error_message.as_deref()),
+            _ => None,
+        }
+    }
+}
+
+fn first_diagnostic(recipients: &[SesRecipient]) -> Option<&str> {
+    recipients.iter().find_map(|r| r.diagnostic_code.as_deref())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bounce_notification() {
+        let event: SesEvent = serde_json::from_str(
+            r#"{
+                "notificationType": "Bounce",
+                "bounce": {
+                    "bounceType": "Permanent",
+                    "bounceSubType": "General",
+                    "bouncedRecipients": [{
+                        "emailAddress": "jane@example.com",
+                        "action": "failed",
+                        "status": "5.1.1",
+                        "diagnosticCode": "smtp; 550 5.1.1 user unknown"
+                    }],
+                    "timestamp": "2016-01-27T14:59:38.237Z",
+                    "feedbackId": "00000138111222aa-33322211-cccc-cccc-cccc-ddddaaaa0680-000000",
+                    "reportingMTA": "dsn; a8-70.smtp-out.amazonses.com"
+                },
+                "mail": {
+                    "timestamp": "2016-01-27T14:59:37.000Z",
+                    "messageId": "0000014644fe5ef6-9a483358-9170-4cb4-a269-f5dcdf415321-000000",
+                    "source": "john@example.com",
+                    "destination": ["jane@example.com"],
+                    "tags": {"request_id": ["0191f4b4-0000-7000-8000-000000000000"]}
+                }
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(event.event_type, SesEventType::Bounce);
+        assert_eq!(
+            event.request_id(),
+            Some("0191f4b4-0000-7000-8000-000000000000")
+        );
+        assert_eq!(event.bounce_type(), Some("Permanent"));
+        assert_eq!(event.bounce_sub_type(), Some("General"));
+        assert_eq!(event.diagnostic(), Some("smtp; 550 5.1.1 user unknown"));
+        assert_eq!(event.suppressed_recipients(), vec!["jane@example.com"]);
+        assert_eq!(
+            event.occurred_at().unwrap().to_rfc3339(),
+            "2016-01-27T14:59:38.237+00:00"
+        );
+    }
+
+    #[test]
+    fn test_transient_bounce_not_suppressed() {
+        let event: SesEvent = serde_json::from_str(
+            r#"{
+                "eventType": "Bounce",
+                "mail": {"messageId": "abc"},
+                "bounce": {
+                    "bounceType": "Transient",
+                    "bounceSubType": "MailboxFull",
+                    "bouncedRecipients": [{"emailAddress": "jane@example.com"}]
+                }
+            }"#,
+        )
+        .unwrap();
+        assert!(event.suppressed_recipients().is_empty());
+    }
+
+    #[test]
+    fn test_event_publishing_format() {
+        // 구성 세트 이벤트 게시는 eventType과 이벤트별 키를 사용
+        let event: SesEvent = serde_json::from_str(
+            r#"{
+                "eventType": "Rendering Failure",
+                "mail": {"timestamp": "2017-08-09T22:00:19.652Z", "messageId": "abc", "tags": {}},
+                "failure": {"errorMessage": "Attribute 'name' is not present", "templateName": "welcome"}
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(event.event_type, SesEventType::RenderingFailure);
+        assert_eq!(event.event_type.as_str(), "RenderingFailure");
+        assert_eq!(event.request_id(), None);
+        assert_eq!(event.diagnostic(), Some("Attribute 'name' is not present"));
+
+        let event: SesEvent = serde_json::from_str(
+            r#"{
+                "eventType": "Complaint",
+                "mail": {"messageId": "abc"},
+                "complaint": {
+                    "complainedRecipients": [{"emailAddress": "jane@example.com"}],
+                    "timestamp": "2017-08-05T00:41:02.669Z",
+                    "complaintFeedbackType": "abuse"
+                }
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(event.complaint_feedback_type(), Some("abuse"));
+        assert_eq!(event.suppressed_recipients(), vec!["jane@example.com"]);
+        assert!(event.mail.tags.is_empty());
+
+        assert!(serde_json::from_str::<SesEvent>(
+            r#"{"notificationType": "AmazonSnsSubscriptionSucceeded", "mail": {}}"#
+        )
+        .is_err());
+    }
+}
@@ -0,0 +1,27 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+/// 수신 거부 사유 (SES 이벤트나 수신 거부 링크로 자동 등록되거나 API로 직접 등록)
+pub const SUPPRESSION_REASON_BOUNCE: &str = "Bounce";
+pub const SUPPRESSION_REASON_COMPLAINT: &str = "Complaint";
+pub const SUPPRESSION_REASON_MANUAL: &str = "Manual";
+pub const SUPPRESSION_REASON_UNSUBSCRIBE: &str = "Unsubscribe";
+
+/// 발송 대상에서 제외할 수신자 (topic_id가 없으면 모든 토픽에 적용)
+#[derive(Debug, Clone, FromRow, Serialize, Deserialize)]
+pub struct Suppression {
+    pub id: i32,
+    pub email: String,
+    pub topic_id: Option<String>,
+    pub reason: String,
+    pub request_id: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// 수신 거부 목록은 대소문자를 구분하지 않도록 정규화한 주소로 저장
+pub fn normalize_email(email: &str) -> String {
+    email.trim().to_lowercase()
+}
@@ -0,0 +1,23 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+
+#[derive(Debug, Clone, FromRow, Serialize, Deserialize)]
+pub struct EmailTemplate {
+    pub id: i32,
+    pub name: String,
+    pub description: Option<String>,
+    pub latest_version: i32,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, FromRow, Serialize, Deserialize)]
+pub struct EmailTemplateVersion {
+    pub id: i32,
+    pub template_id: i32,
+    pub version: i32,
+    pub subject: String,
+    pub content: String,
+    pub created_at: DateTime<Utc>,
+}
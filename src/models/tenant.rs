@@ -0,0 +1,154 @@
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
+use chrono::{DateTime, Utc};
+use ring::rand::{SecureRandom, SystemRandom};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use sqlx::FromRow;
+use std::{fmt, str::FromStr};
+
+/// 기존 데이터와 환경 변수 API_KEY가 속하는 기본 테넌트
+pub const DEFAULT_TENANT_ID: i32 = 1;
+
+/// 발급되는 API 키의 접두사 (로그나 설정 파일에서 키를 식별하기 위한 용도)
+const API_KEY_PREFIX: &str = "mk_";
+
+/// 목록 조회 시 키를 구분할 수 있도록 저장하는 앞부분 길이
+const API_KEY_DISPLAY_LEN: usize = 11;
+
+/// API 키 권한 (`admin`은 모든 권한과 테넌트/API 키 관리를 포함)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ApiScope {
+    MessagesSend,
+    MessagesRead,
+    StatsRead,
+    TemplatesRead,
+    TemplatesWrite,
+    SuppressionsRead,
+    SuppressionsWrite,
+    Admin,
+}
+
+impl ApiScope {
+    pub const ALL: [ApiScope; 8] = [
+        ApiScope::MessagesSend,
+        ApiScope::MessagesRead,
+        ApiScope::StatsRead,
+        ApiScope::TemplatesRead,
+        ApiScope::TemplatesWrite,
+        ApiScope::SuppressionsRead,
+        ApiScope::SuppressionsWrite,
+        ApiScope::Admin,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ApiScope::MessagesSend => "messages:send",
+            ApiScope::MessagesRead => "messages:read",
+            ApiScope::StatsRead => "stats:read",
+            ApiScope::TemplatesRead => "templates:read",
+            ApiScope::TemplatesWrite => "templates:write",
+            ApiScope::SuppressionsRead => "suppressions:read",
+            ApiScope::SuppressionsWrite => "suppressions:write",
+            ApiScope::Admin => "admin",
+        }
+    }
+}
+
+impl fmt::Display for ApiScope {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for ApiScope {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        ApiScope::ALL
+            .into_iter()
+            .find(|scope| scope.as_str() == s)
+            .ok_or_else(|| format!("Unknown API key scope: {}", s))
+    }
+}
+
+#[derive(Debug, Clone, FromRow, Serialize, Deserialize)]
+pub struct Tenant {
+    pub id: i32,
+    pub name: String,
+    pub created_at: DateTime<Utc>,
+}
+
+/// 테넌트에 발급된 API 키 (원문은 저장하지 않고 해시만 보관)
+#[derive(Debug, Clone, FromRow, Serialize, Deserialize)]
+pub struct ApiKey {
+    pub id: i32,
+    pub tenant_id: i32,
+    pub name: String,
+    pub key_prefix: String,
+    pub scopes: Vec<String>,
+    /// 설정되면 이 접두사로 시작하는 토픽만 다룰 수 있음
+    pub topic_prefix: Option<String>,
+    pub expires_at: Option<DateTime<Utc>>,
+    /// 키별 제한 (None이면 설정의 기본값 사용)
+    pub requests_per_sec: Option<i32>,
+    pub daily_recipient_quota: Option<i64>,
+    pub monthly_recipient_quota: Option<i64>,
+    pub last_used_at: Option<DateTime<Utc>>,
+    pub revoked_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+}
+
+/// 새 API 키 원문 생성 (발급 응답에서 한 번만 노출)
+pub fn generate_api_key() -> Result<String, ring::error::Unspecified> {
+    let mut bytes = [0u8; 32];
+    SystemRandom::new().fill(&mut bytes)?;
+    Ok(format!(
+        "{}{}",
+        API_KEY_PREFIX,
+        URL_SAFE_NO_PAD.encode(bytes)
+    ))
+}
+
+/// API 키 원문의 SHA-256 해시 (hex)
+pub fn hash_api_key(key: &str) -> String {
+    format!("{:x}", Sha256::digest(key.as_bytes()))
+}
+
+/// 목록에 표시할 키 앞부분
+pub fn api_key_prefix(key: &str) -> String {
+    key.chars().take(API_KEY_DISPLAY_LEN).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_generate_api_key() {
+        let key = generate_api_key().unwrap();
+        let other = generate_api_key().unwrap();
+
+        assert!(key.starts_with(API_KEY_PREFIX));
+        assert_eq!(key.len(), API_KEY_PREFIX.len() + 43);
+        assert_ne!(key, other);
+        assert_eq!(api_key_prefix(&key), key[..API_KEY_DISPLAY_LEN]);
+    }
+
+    #[test]
+    fn test_api_scope_round_trip() {
+        for scope in ApiScope::ALL {
+            assert_eq!(scope.as_str().parse::<ApiScope>(), Ok(scope));
+        }
+        assert!("messages:*".parse::<ApiScope>().is_err());
+    }
+
+    #[test]
+    fn test_hash_api_key() {
+        let hash = hash_api_key("mk_test");
+
+        assert_eq!(hash.len(), 64);
+        assert_eq!(hash, hash_api_key("mk_test"));
+        assert_ne!(hash, hash_api_key("mk_other"));
+    }
+}
@@ -0,0 +1,108 @@
+use crate::config::LimitsConfig;
+use chrono::{DateTime, Datelike, NaiveDate, Utc};
+
+/// API 키에 적용되는 제한 (None이면 제한 없음)
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ApiKeyLimits {
+    pub requests_per_sec: Option<u32>,
+    pub daily_recipients: Option<i64>,
+    pub monthly_recipients: Option<i64>,
+}
+
+impl ApiKeyLimits {
+    /// 키에 지정된 제한을 우선하고, 없으면 설정의 기본값 사용 (0은 제한 없음)
+    pub fn resolve(
+        requests_per_sec: Option<i32>,
+        daily_recipients: Option<i64>,
+        monthly_recipients: Option<i64>,
+        defaults: &LimitsConfig,
+    ) -> Self {
+        Self {
+            requests_per_sec: requests_per_sec
+                .map(|limit| limit.max(0) as u32)
+                .or(Some(defaults.api_requests_per_sec))
+                .filter(|limit| *limit > 0),
+            daily_recipients: daily_recipients
+                .or(Some(defaults.daily_recipient_quota))
+                .filter(|limit| *limit > 0),
+            monthly_recipients: monthly_recipients
+                .or(Some(defaults.monthly_recipient_quota))
+                .filter(|limit| *limit > 0),
+        }
+    }
+}
+
+/// 월간 사용량 집계 구간의 첫날 (UTC)
+pub fn month_start(day: NaiveDate) -> NaiveDate {
+    day.with_day(1).unwrap_or(day)
+}
+
+/// 다음 달 첫날 (월간 사용량 초기화 시점)
+pub fn next_month_start(day: NaiveDate) -> NaiveDate {
+    let (year, month) = match day.month() {
+        12 => (day.year() + 1, 1),
+        month => (day.year(), month + 1),
+    };
+    NaiveDate::from_ymd_opt(year, month, 1).unwrap_or(day)
+}
+
+/// 다음 날 첫 시각 (일간 사용량 초기화 시점)
+pub fn next_day_start(day: NaiveDate) -> NaiveDate {
+    day.succ_opt().unwrap_or(day)
+}
+
+/// 주어진 날짜의 UTC 자정
+pub fn start_of_day(day: NaiveDate) -> DateTime<Utc> {
+    day.and_time(chrono::NaiveTime::MIN).and_utc()
+}
+
+/// `Retry-After`로 돌려줄 남은 시간(초, 최소 1초)
+pub fn secs_until(now: DateTime<Utc>, until: DateTime<Utc>) -> u64 {
+    (until - now).num_seconds().max(1) as u64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_usage_periods() {
+        let day = NaiveDate::from_ymd_opt(2024, 12, 31).unwrap();
+
+        assert_eq!(
+            month_start(day),
+            NaiveDate::from_ymd_opt(2024, 12, 1).unwrap()
+        );
+        assert_eq!(
+            next_month_start(day),
+            NaiveDate::from_ymd_opt(2025, 1, 1).unwrap()
+        );
+        assert_eq!(
+            next_day_start(day),
+            NaiveDate::from_ymd_opt(2025, 1, 1).unwrap()
+        );
+
+        let now = start_of_day(day) + chrono::Duration::hours(23);
+        assert_eq!(secs_until(now, start_of_day(next_day_start(day))), 3600);
+        assert_eq!(secs_until(now, now), 1);
+    }
+
+    #[test]
+    fn test_resolve_limits() {
+        let defaults = LimitsConfig {
+            api_requests_per_sec: 10,
+            daily_recipient_quota: 0,
+            monthly_recipient_quota: 100_000,
+        };
+
+        let limits = ApiKeyLimits::resolve(None, Some(500), Some(0), &defaults);
+        assert_eq!(
+            limits,
+            ApiKeyLimits {
+                requests_per_sec: Some(10),
+                daily_recipients: Some(500),
+                monthly_recipients: None,
+            }
+        );
+    }
+}
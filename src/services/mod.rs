@@ -1,2 +1,9 @@
+pub mod plaintext;
 pub mod producer;
+pub mod rate_limit;
+pub mod reaper;
+pub mod renderer;
 pub mod scheduler;
+pub mod sns;
+pub mod token;
+pub mod tracking;
@@ -0,0 +1,221 @@
+use regex::{Captures, Regex};
+
+lazy_static::lazy_static! {
+    // 텍스트로 표현할 수 없는 요소는 내용까지 제거
+    static ref HIDDEN_BLOCK_REGEX: Regex = Regex::new(
+        r"(?is)<!--.*?-->|<head\b.*?</head\s*>|<script\b.*?</script\s*>|<style\b.*?</style\s*>"
+    ).unwrap();
+    static ref TAG_REGEX: Regex = Regex::new(r"(?s)<(/?)([a-zA-Z][a-zA-Z0-9]*)([^>]*)>").unwrap();
+    static ref HREF_REGEX: Regex = Regex::new(
+        r#"(?i)\bhref\s*=\s*(?:"([^"]*)"|'([^']*)'|([^\s>]+))"#
+    ).unwrap();
+    static ref ENTITY_REGEX: Regex = Regex::new(r"&(#[0-9]+|#[xX][0-9a-fA-F]+|[a-zA-Z]+);").unwrap();
+}
+
+/// HTML 본문에서 읽기 쉬운 텍스트 본문 생성
+///
+/// 링크는 본문에 `[n]` 번호로 표시하고 하단에 각주로 URL을 나열하며, 이미지는 제외합니다.
+pub fn html_to_text(html: &str) -> String {
+    let html = HIDDEN_BLOCK_REGEX.replace_all(html, "");
+    let mut writer = TextWriter::default();
+    let mut open_link: Option<String> = None;
+    let mut last = 0;
+
+    for caps in TAG_REGEX.captures_iter(&html) {
+        let tag = caps.get(0).unwrap();
+        writer.push_text(&decode_entities(&html[last..tag.start()]));
+        last = tag.end();
+
+        let closing = &caps[1] == "/";
+        let name = caps[2].to_ascii_lowercase();
+        match (name.as_str(), closing) {
+            ("br", _) => writer.push_break(1),
+            ("p" | "h1" | "h2" | "h3" | "h4" | "h5" | "h6" | "table" | "ul" | "ol", _) => {
+                writer.push_break(2)
+            }
+            ("blockquote" | "div" | "section" | "article" | "header" | "footer" | "tr", _) => {
+                writer.push_break(1)
+            }
+            ("hr", _) => {
+                writer.push_break(1);
+                writer.push_text("--------");
+                writer.push_break(1);
+            }
+            ("li", false) => {
+                writer.push_break(1);
+                writer.push_text("- ");
+            }
+            ("td" | "th", false) => writer.push_text(" "),
+            ("a", false) => open_link = href(&caps[3]),
+            ("a", true) => {
+                if let Some(url) = open_link.take() {
+                    writer.push_footnote(url);
+                }
+            }
+            _ => {}
+        }
+    }
+    writer.push_text(&decode_entities(&html[last..]));
+
+    writer.finish()
+}
+
+/// 각주로 남길 링크 주소 (페이지 내 앵커나 스크립트 링크는 제외)
+fn href(attributes: &str) -> Option<String> {
+    let caps = HREF_REGEX.captures(attributes)?;
+    let url = caps.get(1).or(caps.get(2)).or(caps.get(3))?.as_str();
+    let url = decode_entities(url).trim().to_string();
+
+    let lower = url.to_ascii_lowercase();
+    if url.is_empty() || url.starts_with('#') || lower.starts_with("javascript:") {
+        return None;
+    }
+    Some(url)
+}
+
+pub fn decode_entities(text: &str) -> String {
+    ENTITY_REGEX
+        .replace_all(text, |caps: &Captures| {
+            let entity = &caps[1];
+            let decoded = match entity {
+                "amp" => Some('&'),
+                "lt" => Some('<'),
+                "gt" => Some('>'),
+                "quot" => Some('"'),
+                "apos" => Some('\''),
+                "nbsp" => Some(' '),
+                _ if entity.starts_with("#x") || entity.starts_with("#X") => {
+                    u32::from_str_radix(&entity[2..], 16)
+                        .ok()
+                        .and_then(char::from_u32)
+                }
+                _ if entity.starts_with('#') => {
+                    entity[1..].parse::<u32>().ok().and_then(char::from_u32)
+                }
+                _ => None,
+            };
+            decoded.map_or_else(|| caps[0].to_string(), String::from)
+        })
+        .into_owned()
+}
+
+#[derive(Default)]
+struct TextWriter {
+    text: String,
+    links: Vec<String>,
+    /// 연속된 줄바꿈 수 (블록 요소 경계에서 빈 줄이 과도하게 생기지 않도록)
+    pending_breaks: usize,
+}
+
+impl TextWriter {
+    /// 공백을 하나로 합쳐 추가
+    fn push_text(&mut self, text: &str) {
+        if text.starts_with(char::is_whitespace) {
+            self.push_space();
+        }
+        for (i, word) in text.split_whitespace().enumerate() {
+            self.push_word(word, i > 0);
+        }
+        if text.ends_with(char::is_whitespace) {
+            self.push_space();
+        }
+    }
+
+    fn push_word(&mut self, word: &str, leading_space: bool) {
+        if self.pending_breaks > 0 {
+            if !self.text.is_empty() {
+                self.text.push_str(&"\n".repeat(self.pending_breaks));
+            }
+            self.pending_breaks = 0;
+        } else if leading_space {
+            self.push_space();
+        }
+        self.text.push_str(word);
+    }
+
+    fn push_space(&mut self) {
+        if self.pending_breaks == 0 && !self.text.is_empty() && !self.text.ends_with([' ', '\n']) {
+            self.text.push(' ');
+        }
+    }
+
+    fn push_break(&mut self, count: usize) {
+        let trimmed = self.text.trim_end_matches(' ').len();
+        self.text.truncate(trimmed);
+        self.pending_breaks = self.pending_breaks.max(count);
+    }
+
+    /// 같은 URL은 같은 번호로 표시
+    fn push_footnote(&mut self, url: String) {
+        let index = match self.links.iter().position(|link| *link == url) {
+            Some(index) => index,
+            None => {
+                self.links.push(url);
+                self.links.len() - 1
+            }
+        };
+        self.push_word(&format!("[{}]", index + 1), true);
+    }
+
+    fn finish(self) -> String {
+        let mut text = self.text.trim().to_string();
+        if !self.links.is_empty() {
+            text.push_str("\n\n");
+            for (i, link) in self.links.iter().enumerate() {
+                text.push_str(&format!("[{}] {}\n", i + 1, link));
+            }
+            text.truncate(text.trim_end().len());
+        }
+        text
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_html_to_text_blocks_and_whitespace() {
+        // 블록 요소는 줄바꿈으로, 연속 공백은 하나로
+        let html = "<h1>Welcome</h1>\n  <p>Hello,\n   <b>Lee</b>!</p><p>Line one<br>Line two</p>";
+        assert_eq!(
+            html_to_text(html),
+            "Welcome\n\nHello, Lee!\n\nLine one\nLine two"
+        );
+    }
+
+    #[test]
+    fn test_html_to_text_links_as_footnotes() {
+        // 링크는 번호로 표시하고 같은 URL은 같은 번호 사용
+        let html = r##"<p>Read <a href="https://example.com/a?x=1&amp;y=2">the post</a>,
+            then <a href='https://example.com/b'>reply</a> or <a href="https://example.com/a?x=1&amp;y=2">re-read</a>.
+            <a href="#top">Top</a></p>"##;
+        assert_eq!(
+            html_to_text(html),
+            "Read the post [1], then reply [2] or re-read [1]. Top\n\n\
+             [1] https://example.com/a?x=1&y=2\n\
+             [2] https://example.com/b"
+        );
+    }
+
+    #[test]
+    fn test_html_to_text_inline_spacing() {
+        // 인라인 요소 사이의 공백은 유지
+        assert_eq!(html_to_text("<b>Hello</b> <i>world</i>"), "Hello world");
+    }
+
+    #[test]
+    fn test_html_to_text_lists_and_entities() {
+        let html = "<ul><li>Fish &amp; chips</li><li>&lt;3 &#8364;5 &#x41;</li></ul><p>Done</p>";
+        assert_eq!(html_to_text(html), "- Fish & chips\n- <3 €5 A\n\nDone");
+    }
+
+    #[test]
+    fn test_html_to_text_strips_hidden_content_and_images() {
+        // 스타일/스크립트/주석과 이미지(트래킹 픽셀 포함)는 텍스트에서 제외
+        let html = r#"<html><head><title>T</title><style>p { color: red; }</style></head>
+            <body><!-- hidden --><p>Visible</p><script>alert(1)</script>
+            <img src="https://example.com/v1/events/open?requestId=1" width="1" height="1" alt=""></body></html>"#;
+        assert_eq!(html_to_text(html), "Visible");
+    }
+}
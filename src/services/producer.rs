@@ -2,15 +2,16 @@ use crate::{
     config::NatsConfig,
     error::{AppError, Result},
     models::email::*,
+    services::token::TokenSigner,
 };
 use async_nats::jetstream::{self, stream::Config as StreamConfig};
 use serde::Serialize;
-use std::time::Duration;
+use std::{collections::BTreeMap, time::Duration};
 use tracing::{error, info};
 
 pub struct ProducerService {
     jetstream: jetstream::Context,
-    subject: String,
+    config: NatsConfig,
 }
 
 #[derive(Serialize)]
@@ -19,6 +20,28 @@ pub struct EmailPublishPayload {
     email: String,
     subject: String,
     body: String,
+    /// multipart/alternative 구성을 위한 텍스트 본문
+    text_body: String,
+    /// 메일에 추가할 헤더 (요청 헤더, List-Unsubscribe 등)
+    headers: BTreeMap<String, String>,
+    /// 발신자 (None이면 발송 서비스의 기본 발신자 사용)
+    from_email: Option<String>,
+    from_name: Option<String>,
+    reply_to: Vec<String>,
+    cc: Vec<String>,
+    bcc: Vec<String>,
+    /// 첨부 파일 (내용은 `url`에서 내려받음)
+    attachments: Vec<EmailAttachmentPayload>,
+}
+
+#[derive(Serialize)]
+pub struct EmailAttachmentPayload {
+    filename: String,
+    content_type: String,
+    /// 인라인 이미지의 Content-ID
+    content_id: Option<String>,
+    size: i32,
+    url: String,
 }
 
 impl ProducerService {
@@ -31,28 +54,47 @@ impl ProducerService {
         let jetstream = jetstream::new(client);
 
         // 스트림이 존재하지 않으면 생성
+        let subjects = config.subjects();
         let stream_config = StreamConfig {
             name: config.stream.clone(),
-            subjects: vec![config.subject.clone()],
+            subjects: subjects.clone(),
             max_age: Duration::from_secs(24 * 60 * 60), // 24 hours
             max_messages: 1_000_000,
             max_bytes: 1_000_000_000, // 1GB
             ..Default::default()
         };
 
-        match jetstream.get_or_create_stream(stream_config).await {
-            Ok(_stream_info) => {
-                info!("NATS stream '{}' ready", config.stream);
-            }
+        let stream = match jetstream.get_or_create_stream(stream_config).await {
+            Ok(stream) => stream,
             Err(e) => {
                 error!("Failed to create NATS stream '{}': {}", config.stream, e);
                 return Err(AppError::Nats(e.to_string()));
             }
+        };
+
+        // 기존 스트림에 우선순위별 subject가 없으면 추가
+        let mut stream_config = stream.cached_info().config.clone();
+        let missing: Vec<String> = subjects
+            .into_iter()
+            .filter(|subject| !stream_config.subjects.contains(subject))
+            .collect();
+        if !missing.is_empty() {
+            info!(
+                "Adding subjects {:?} to NATS stream '{}'",
+                missing, config.stream
+            );
+            stream_config.subjects.extend(missing);
+            jetstream.update_stream(&stream_config).await.map_err(|e| {
+                error!("Failed to update NATS stream '{}': {}", config.stream, e);
+                AppError::Nats(e.to_string())
+            })?;
         }
 
+        info!("NATS stream '{}' ready", config.stream);
+
         Ok(Self {
             jetstream,
-            subject: config.subject.clone(),
+            config: config.clone(),
         })
     }
 
@@ -60,32 +102,52 @@ impl ProducerService {
         &self,
         request: &EmailRequestWithContent,
         server_host: &str,
+        signer: &TokenSigner,
     ) -> Result<()> {
-        let content_with_tracking = request.content_with_tracking(server_host);
+        let content_with_tracking = request.content_with_tracking(server_host, signer);
 
         let payload = EmailPublishPayload {
             uuid: request.id.to_string(),
             email: request.to_email.clone(),
-            subject: request.subject.as_deref().unwrap_or("").to_string(),
+            subject: request.rendered_subject(),
             body: content_with_tracking,
+            text_body: request.rendered_text(),
+            headers: request.mail_headers(server_host, signer),
+            from_email: request.from_email.clone(),
+            from_name: request.from_name.clone(),
+            reply_to: request.reply_to.clone(),
+            cc: request.cc.clone(),
+            bcc: request.bcc.clone(),
+            attachments: request
+                .attachment_infos()
+                .into_iter()
+                .map(|attachment| EmailAttachmentPayload {
+                    url: request.generate_attachment_url(server_host, attachment.id, signer),
+                    filename: attachment.filename,
+                    content_type: attachment.content_type,
+                    content_id: attachment.cid,
+                    size: attachment.size,
+                })
+                .collect(),
         };
 
         let payload_bytes =
-            rmp_serde::to_vec(&payload).map_err(|e| AppError::Nats(e.to_string()))?;
+            rmp_serde::to_vec(&payload).map_err(|e| AppError::Internal(e.to_string()))?;
 
-        match self
-            .jetstream
-            .publish(self.subject.clone(), payload_bytes.into())
-            .await
-        {
+        // 우선순위별 subject로 발행하여 발송기가 전용 컨슈머로 처리할 수 있도록 함
+        let subject = self.config.subject_for(request.priority).to_string();
+
+        match self.jetstream.publish(subject, payload_bytes.into()).await {
             Ok(ack) => {
                 let sequence = ack
                     .await
                     .map_err(|e| AppError::Nats(e.to_string()))?
                     .sequence;
                 info!(
-                    "Message published successfully: request_id={}, stream_seq={}",
-                    request.id, sequence
+                    "Message published successfully: request_id={}, priority={}, stream_seq={}",
+                    request.id,
+                    request.priority.as_str(),
+                    sequence
                 );
                 Ok(())
             }
@@ -103,7 +165,7 @@ impl ProducerService {
     pub async fn health_check(&self) -> Result<()> {
         // Simple health check by getting stream info
         self.jetstream
-            .get_stream(&self.subject)
+            .get_stream(&self.config.stream)
             .await
             .map_err(|e| AppError::Nats(e.to_string()))?;
         Ok(())
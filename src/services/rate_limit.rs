@@ -0,0 +1,181 @@
+use std::{
+    collections::HashMap,
+    hash::Hash,
+    sync::Mutex,
+    time::{Duration, Instant},
+};
+use tokio::sync::Mutex as AsyncMutex;
+
+/// 토큰 버킷 (초당 `rate`개씩 최대 `capacity`개까지 충전)
+#[derive(Debug, Clone)]
+pub struct TokenBucket {
+    rate: f64,
+    capacity: f64,
+    tokens: f64,
+    updated_at: Instant,
+}
+
+impl TokenBucket {
+    /// 가득 찬 상태로 시작하는 버킷
+    pub fn new(rate: f64, capacity: f64) -> Self {
+        Self {
+            rate,
+            capacity,
+            tokens: capacity,
+            updated_at: Instant::now(),
+        }
+    }
+
+    pub fn rate(&self) -> f64 {
+        self.rate
+    }
+
+    pub fn capacity(&self) -> f64 {
+        self.capacity
+    }
+
+    fn refill(&mut self, now: Instant) {
+        let elapsed = now.saturating_duration_since(self.updated_at).as_secs_f64();
+        self.tokens = (self.tokens + elapsed * self.rate).min(self.capacity);
+        self.updated_at = now;
+    }
+
+    /// 지금 가져갈 수 있는 토큰 수
+    pub fn available_at(&mut self, now: Instant) -> u64 {
+        self.refill(now);
+        self.tokens.floor() as u64
+    }
+
+    /// `tokens`개를 가져갈 수 있을 때까지의 시간 (마지막으로 충전한 시점 기준)
+    pub fn until_available(&self, tokens: f64) -> Duration {
+        Duration::from_secs_f64((tokens - self.tokens).max(0.0) / self.rate)
+    }
+
+    /// 토큰을 가져오고, 부족하면 필요한 토큰이 충전될 때까지의 대기 시간을 반환
+    pub fn try_acquire_at(&mut self, tokens: f64, now: Instant) -> Result<(), Duration> {
+        self.refill(now);
+
+        if self.tokens >= tokens {
+            self.tokens -= tokens;
+            Ok(())
+        } else {
+            Err(Duration::from_secs_f64((tokens - self.tokens) / self.rate))
+        }
+    }
+}
+
+/// 여러 작업이 공유하는 토큰 버킷 (토큰이 없으면 충전될 때까지 대기)
+pub struct RateLimiter {
+    bucket: AsyncMutex<TokenBucket>,
+}
+
+impl RateLimiter {
+    /// 초당 `rate`개 (최대 1초 분량까지 몰아서 허용, 최소 1개)
+    pub fn new(rate: f64) -> Self {
+        Self {
+            bucket: AsyncMutex::new(TokenBucket::new(rate, rate.max(1.0))),
+        }
+    }
+
+    /// 토큰 하나를 가져올 때까지 대기
+    ///
+    /// 대기 중에도 잠금을 유지하므로 대기자는 도착한 순서대로 토큰을 받습니다.
+    pub async fn acquire(&self) {
+        let mut bucket = self.bucket.lock().await;
+        while let Err(wait) = bucket.try_acquire_at(1.0, Instant::now()) {
+            tokio::time::sleep(wait).await;
+        }
+    }
+}
+
+/// 키별 토큰 버킷 (API 키별 요청 수 제한 등)
+pub struct KeyedRateLimiter<K> {
+    buckets: Mutex<HashMap<K, TokenBucket>>,
+}
+
+impl<K: Eq + Hash> Default for KeyedRateLimiter<K> {
+    fn default() -> Self {
+        Self {
+            buckets: Mutex::new(HashMap::new()),
+        }
+    }
+}
+
+impl<K: Eq + Hash> KeyedRateLimiter<K> {
+    /// 초당 `rate`개(최대 1초 분량까지 몰아서 허용)로 키의 토큰 하나를 가져오기
+    ///
+    /// 키의 제한이 바뀌면 버킷을 새로 만듭니다.
+    pub fn check(&self, key: K, rate: u32) -> Result<(), Duration> {
+        let rate = rate as f64;
+        let mut buckets = self.buckets.lock().unwrap_or_else(|e| e.into_inner());
+        let bucket = buckets
+            .entry(key)
+            .and_modify(|bucket| {
+                if bucket.rate() != rate {
+                    *bucket = TokenBucket::new(rate, rate);
+                }
+            })
+            .or_insert_with(|| TokenBucket::new(rate, rate));
+
+        bucket.try_acquire_at(1.0, Instant::now())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_token_bucket_refill() {
+        let mut bucket = TokenBucket::new(2.0, 2.0);
+        let start = bucket.updated_at;
+
+        assert!(bucket.try_acquire_at(1.0, start).is_ok());
+        assert!(bucket.try_acquire_at(1.0, start).is_ok());
+        let wait = bucket.try_acquire_at(1.0, start).unwrap_err();
+        assert_eq!(wait, Duration::from_millis(500));
+
+        // 0.5초 후 토큰 하나가 충전되고, 오래 지나도 용량을 넘지 않음
+        assert!(bucket
+            .try_acquire_at(1.0, start + Duration::from_millis(500))
+            .is_ok());
+        let later = start + Duration::from_secs(60);
+        assert!(bucket.try_acquire_at(2.0, later).is_ok());
+        assert!(bucket.try_acquire_at(1.0, later).is_err());
+        assert_eq!(bucket.until_available(1.0), Duration::from_millis(500));
+        assert_eq!(bucket.until_available(0.0), Duration::ZERO);
+        assert_eq!(
+            bucket.until_available(bucket.capacity()),
+            Duration::from_secs(1)
+        );
+        assert_eq!(bucket.available_at(later + Duration::from_millis(750)), 1);
+    }
+
+    #[tokio::test]
+    async fn test_rate_limiter_paces_acquires() {
+        let limiter = RateLimiter::new(20.0);
+        let start = Instant::now();
+
+        // 1초 분량은 바로 허용되고, 이후에는 초당 20개 속도로 대기
+        for _ in 0..20 {
+            limiter.acquire().await;
+        }
+        assert!(start.elapsed() < Duration::from_millis(40));
+        for _ in 0..2 {
+            limiter.acquire().await;
+        }
+        assert!(start.elapsed() >= Duration::from_millis(90));
+    }
+
+    #[test]
+    fn test_keyed_rate_limiter() {
+        let limiter = KeyedRateLimiter::default();
+
+        assert!(limiter.check(1, 1).is_ok());
+        assert!(limiter.check(1, 1).is_err());
+        // 다른 키는 별도의 버킷을 사용
+        assert!(limiter.check(2, 1).is_ok());
+        // 제한이 바뀌면 새 버킷으로 시작
+        assert!(limiter.check(1, 5).is_ok());
+    }
+}
@@ -0,0 +1,141 @@
+use crate::{config::AppConfig, error::Result, models::email::*};
+use chrono::Utc;
+use sqlx::PgPool;
+use std::{
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
+use tokio::time;
+use tracing::{debug, error, info, warn};
+
+/// Processing 상태에 멈춘 요청을 주기적으로 복구하는 서비스
+///
+/// 프로세스 종료나 발행 태스크 패닉으로 결과가 기록되지 못한 요청을
+/// 타임아웃 이후 Created로 되돌리고, 복구 횟수를 초과하면 Failed로 처리합니다.
+#[derive(Clone)]
+pub struct ReaperService {
+    db: PgPool,
+    config: Arc<AppConfig>,
+    totals: Arc<ReaperTotals>,
+}
+
+#[derive(Debug, Default)]
+struct ReaperTotals {
+    recovered: AtomicU64,
+    failed: AtomicU64,
+}
+
+#[derive(Debug, Default, Clone, Copy)]
+pub struct ReaperStats {
+    pub recovered: u64,
+    pub failed: u64,
+}
+
+impl ReaperStats {
+    pub fn total(&self) -> u64 {
+        self.recovered + self.failed
+    }
+}
+
+impl ReaperService {
+    pub fn new(db: PgPool, config: Arc<AppConfig>) -> Self {
+        Self {
+            db,
+            config,
+            totals: Arc::new(ReaperTotals::default()),
+        }
+    }
+
+    pub async fn run(&self) -> Result<()> {
+        let mut interval = time::interval(Duration::from_secs(
+            self.config.scheduler.reaper_interval_secs,
+        ));
+        interval.set_missed_tick_behavior(time::MissedTickBehavior::Skip);
+
+        info!(
+            "🧹 Processing reaper started: timeout={}s, max_recoveries={}, interval={}s",
+            self.config.scheduler.processing_timeout_secs,
+            self.config.scheduler.max_recoveries,
+            self.config.scheduler.reaper_interval_secs
+        );
+
+        loop {
+            interval.tick().await;
+
+            match self.reap_stuck_requests().await {
+                Ok(stats) if stats.total() > 0 => {
+                    let totals = self.totals();
+                    warn!(
+                        "🧹 Recovered stuck processing requests: recovered={}, failed={}, total_recovered={}, total_failed={}",
+                        stats.recovered, stats.failed, totals.recovered, totals.failed
+                    );
+                }
+                Ok(_) => {
+                    debug!("🧹 Reaper cycle completed: no stuck requests");
+                }
+                Err(e) => {
+                    error!("🧹 Reaper cycle failed: {:#}", e);
+                }
+            }
+        }
+    }
+
+    /// 서비스 시작 이후 누적 복구 통계
+    pub fn totals(&self) -> ReaperStats {
+        ReaperStats {
+            recovered: self.totals.recovered.load(Ordering::Relaxed),
+            failed: self.totals.failed.load(Ordering::Relaxed),
+        }
+    }
+
+    pub async fn reap_stuck_requests(&self) -> Result<ReaperStats> {
+        let now = Utc::now();
+        let cutoff =
+            now - chrono::Duration::seconds(self.config.scheduler.processing_timeout_secs as i64);
+
+        // SET 절의 recovery_count는 갱신 전 값을 참조
+        let rows = sqlx::query!(
+            r#"
+            UPDATE email_requests
+            SET status = CASE WHEN recovery_count >= $1 THEN $2::smallint ELSE $3::smallint END,
+                error = CASE
+                    WHEN recovery_count >= $1
+                        THEN 'Stuck in processing; gave up after ' || recovery_count || ' recoveries'
+                    ELSE 'Recovered from stuck processing (recovery ' || (recovery_count + 1) || ')'
+                END,
+                recovery_count = recovery_count + 1,
+                updated_at = $4
+            WHERE status = $5 AND updated_at < $6
+            RETURNING status as "status: EmailStatus"
+            "#,
+            self.config.scheduler.max_recoveries,
+            EmailStatus::Failed as i16,
+            EmailStatus::Created as i16,
+            now,
+            EmailStatus::Processing as i16,
+            cutoff
+        )
+        .fetch_all(&self.db)
+        .await?;
+
+        let mut stats = ReaperStats::default();
+        for row in rows {
+            match row.status {
+                EmailStatus::Failed => stats.failed += 1,
+                _ => stats.recovered += 1,
+            }
+        }
+
+        self.totals
+            .recovered
+            .fetch_add(stats.recovered, Ordering::Relaxed);
+        self.totals
+            .failed
+            .fetch_add(stats.failed, Ordering::Relaxed);
+
+        Ok(stats)
+    }
+}
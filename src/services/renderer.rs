@@ -0,0 +1,188 @@
+use regex::{Captures, Regex};
+use std::collections::BTreeMap;
+
+lazy_static::lazy_static! {
+    // {{ name }} 또는 {{ name | default: "기본값" }}
+    static ref PLACEHOLDER_REGEX: Regex = Regex::new(
+        r#"\{\{\s*([a-zA-Z_][a-zA-Z0-9_]*)\s*(?:\|\s*default\s*:\s*"([^"]*)"\s*)?\}\}"#
+    ).unwrap();
+}
+
+/// 치환 값에 적용할 이스케이프 방식
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Escape {
+    /// HTML 본문용 (&, <, >, ", ' 이스케이프)
+    Html,
+    /// 제목 등 헤더용 (줄바꿈 등 제어 문자를 공백으로 치환)
+    Header,
+    /// 텍스트 본문용 (이스케이프 없음)
+    Text,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Placeholder<'a> {
+    pub name: &'a str,
+    pub default: Option<&'a str>,
+}
+
+/// 템플릿에 포함된 플레이스홀더 목록
+pub fn placeholders(template: &str) -> Vec<Placeholder<'_>> {
+    PLACEHOLDER_REGEX
+        .captures_iter(template)
+        .map(|caps| Placeholder {
+            name: caps.get(1).map_or("", |m| m.as_str()),
+            default: caps.get(2).map(|m| m.as_str()),
+        })
+        .collect()
+}
+
+/// 기본값이 없으면서 vars에도 정의되지 않은 변수 이름 목록 (중복 제거)
+pub fn undefined_variables(template: &str, vars: &BTreeMap<String, String>) -> Vec<String> {
+    let mut undefined: Vec<String> = Vec::new();
+    for placeholder in placeholders(template) {
+        if placeholder.default.is_none()
+            && !vars.contains_key(placeholder.name)
+            && !undefined.iter().any(|name| name == placeholder.name)
+        {
+            undefined.push(placeholder.name.to_string());
+        }
+    }
+    undefined
+}
+
+/// 플레이스홀더를 변수 값(없으면 기본값)으로 치환
+///
+/// 템플릿 자체는 신뢰된 입력으로 보고, 치환되는 값에만 이스케이프를 적용합니다.
+pub fn render(template: &str, vars: &BTreeMap<String, String>, escape: Escape) -> String {
+    PLACEHOLDER_REGEX
+        .replace_all(template, |caps: &Captures| {
+            let name = caps.get(1).map_or("", |m| m.as_str());
+            let value = vars
+                .get(name)
+                .map(String::as_str)
+                .or_else(|| caps.get(2).map(|m| m.as_str()))
+                .unwrap_or("");
+            escape_value(value, escape)
+        })
+        .into_owned()
+}
+
+fn escape_value(value: &str, escape: Escape) -> String {
+    match escape {
+        Escape::Html => escape_html(value),
+        Escape::Header => value
+            .chars()
+            .map(|c| if c.is_control() { ' ' } else { c })
+            .collect(),
+        Escape::Text => value.to_string(),
+    }
+}
+
+pub fn escape_html(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn vars(pairs: &[(&str, &str)]) -> BTreeMap<String, String> {
+        pairs
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn test_placeholders() {
+        // 공백 유무와 기본값 문법을 모두 인식
+        let found = placeholders(r#"Hi {{name}}, {{ plan | default: "free" }} {{ name }}"#);
+        assert_eq!(
+            found,
+            vec![
+                Placeholder {
+                    name: "name",
+                    default: None
+                },
+                Placeholder {
+                    name: "plan",
+                    default: Some("free")
+                },
+                Placeholder {
+                    name: "name",
+                    default: None
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_undefined_variables() {
+        // 기본값이 있는 변수는 정의되지 않아도 허용
+        let template = r#"{{ name }} {{ city }} {{ plan | default: "free" }} {{ name }}"#;
+        assert_eq!(
+            undefined_variables(template, &vars(&[("city", "Seoul")])),
+            vec!["name".to_string()]
+        );
+        assert!(
+            undefined_variables(template, &vars(&[("name", "Lee"), ("city", "Seoul")])).is_empty()
+        );
+    }
+
+    #[test]
+    fn test_render_with_defaults() {
+        // 변수 값이 우선이고, 없으면 기본값 사용
+        let template = r#"Hello {{ name | default: "friend" }}!"#;
+        assert_eq!(
+            render(template, &vars(&[]), Escape::Header),
+            "Hello friend!"
+        );
+        assert_eq!(
+            render(template, &vars(&[("name", "Lee")]), Escape::Header),
+            "Hello Lee!"
+        );
+    }
+
+    #[test]
+    fn test_render_html_escaping() {
+        // 값만 이스케이프되고 템플릿의 마크업은 유지
+        let rendered = render(
+            "<b>{{ name }}</b>",
+            &vars(&[("name", "<script>alert('x')</script> & co")]),
+            Escape::Html,
+        );
+        assert_eq!(
+            rendered,
+            "<b>&lt;script&gt;alert(&#39;x&#39;)&lt;/script&gt; &amp; co</b>"
+        );
+    }
+
+    #[test]
+    fn test_render_header_strips_control_characters() {
+        // 제목에 줄바꿈이 들어가 헤더가 주입되지 않도록 공백으로 치환
+        let rendered = render(
+            "Hi {{ name }}",
+            &vars(&[("name", "Lee\r\nBcc: evil@example.com")]),
+            Escape::Header,
+        );
+        assert_eq!(rendered, "Hi Lee  Bcc: evil@example.com");
+    }
+
+    #[test]
+    fn test_render_leaves_unrelated_braces() {
+        // 변수 문법이 아닌 중괄호는 그대로 유지
+        let template = "{ not a var } {{ 1invalid }}";
+        assert_eq!(render(template, &vars(&[]), Escape::Html), template);
+    }
+}
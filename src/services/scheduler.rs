@@ -2,26 +2,55 @@ use crate::{
     config::AppConfig,
     error::{AppError, Result},
     models::email::*,
-    services::producer::ProducerService,
+    services::{
+        producer::ProducerService,
+        rate_limit::{RateLimiter, TokenBucket},
+        token::TokenSigner,
+    },
 };
-use chrono::Utc;
+use chrono::{DateTime, Utc};
 use sqlx::PgPool;
-use std::{sync::Arc, time::Duration};
+use std::{
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
 use tokio::time;
 use tracing::{debug, error, info, warn};
+use uuid::Uuid;
+
+/// error 컬럼(VARCHAR(255))에 저장 가능한 최대 길이
+const MAX_ERROR_LENGTH: usize = 255;
 
 #[derive(Clone)]
 pub struct SchedulerService {
     db: PgPool,
     producer: Arc<ProducerService>,
     config: Arc<AppConfig>,
+    signer: Arc<TokenSigner>,
+    /// SES 발송 속도 제한에 맞추기 위한 초당 발행 수 제한 (None이면 제한 없음)
+    send_rate: Option<Arc<RateLimiter>>,
+    /// `domain_rate_limits`와 같은 순서의 도메인 그룹별 버킷
+    domain_buckets: Arc<Mutex<Vec<TokenBucket>>>,
 }
 
 impl SchedulerService {
     pub fn new(db: PgPool, producer: Arc<ProducerService>, config: Arc<AppConfig>) -> Self {
+        let send_rate = Some(config.scheduler.send_rate_per_sec)
+            .filter(|rate| *rate > 0.0)
+            .map(|rate| Arc::new(RateLimiter::new(rate)));
+        let domain_buckets = config
+            .scheduler
+            .domain_rate_limits
+            .iter()
+            .map(|limit| TokenBucket::new(limit.per_sec, limit.per_sec.max(1.0)))
+            .collect();
+
         Self {
             db,
             producer,
+            signer: Arc::new(TokenSigner::new(&config.security)),
+            send_rate,
+            domain_buckets: Arc::new(Mutex::new(domain_buckets)),
             config,
         }
     }
@@ -30,11 +59,31 @@ impl SchedulerService {
         let mut interval = time::interval(Duration::from_secs(self.config.scheduler.interval_secs));
         interval.set_missed_tick_behavior(time::MissedTickBehavior::Skip);
 
+        let scheduler = &self.config.scheduler;
         info!(
-            "📧 Email scheduler started: batch_size={}, interval={}s",
-            self.config.scheduler.batch_size, self.config.scheduler.interval_secs
+            "📧 Email scheduler started: batch_size={}, interval={}s, concurrency={}, send_rate={}/s",
+            scheduler.batch_size,
+            scheduler.interval_secs,
+            scheduler.publish_concurrency,
+            scheduler.send_rate_per_sec
         );
 
+        for limit in &scheduler.domain_rate_limits {
+            info!("📧 Domain rate limit: {} = {}/s", limit.name, limit.per_sec);
+        }
+
+        let claim_size = claim_limit(
+            scheduler.batch_size,
+            scheduler.send_rate_per_sec,
+            scheduler.processing_timeout_secs,
+        );
+        if claim_size < scheduler.batch_size {
+            info!(
+                "📧 Claiming at most {} requests per batch so a batch publishes within half of PROCESSING_TIMEOUT_SECS={} at {}/s",
+                claim_size, scheduler.processing_timeout_secs, scheduler.send_rate_per_sec
+            );
+        }
+
         loop {
             interval.tick().await;
 
@@ -61,65 +110,53 @@ impl SchedulerService {
     async fn process_scheduled_emails(&self) -> Result<usize> {
         let mut total_processed = 0;
 
+        // 수신 거부된 수신자의 요청은 주기마다 한 번 중지
+        // (주기 중에 발송 시각이 된 요청은 가져올 때 제외되고 다음 주기에 중지됨)
+        self.stop_suppressed_requests(Utc::now()).await?;
+
         loop {
             let batch_start = std::time::Instant::now();
             let now = Utc::now();
 
-            // FOR UPDATE SKIP LOCKED를 사용하여 요청을 원자적으로 가져오고 업데이트
-            let requests = sqlx::query_as!(
-                EmailRequestWithContent,
-                r#"
-                WITH locked_requests AS (
-                    SELECT er.id
-                    FROM email_requests er
-                    WHERE er.status = $1 
-                      AND (er.scheduled_at <= $2 OR er.scheduled_at IS NULL)
-                    ORDER BY 
-                        CASE WHEN er.scheduled_at IS NULL THEN 0 ELSE 1 END,
-                        er.scheduled_at ASC NULLS FIRST,
-                        er.created_at ASC
-                    LIMIT $3
-                    FOR UPDATE SKIP LOCKED
-                )
-                UPDATE email_requests
-                SET status = $4, updated_at = $5
-                FROM locked_requests lr
-                WHERE email_requests.id = lr.id
-                RETURNING 
-                    email_requests.id,
-                    email_requests.topic_id,
-                    email_requests.to_email,
-                    email_requests.content_id,
-                    email_requests.scheduled_at,
-                    email_requests.status as "status: EmailStatus",
-                    email_requests.error,
-                    email_requests.created_at,
-                    email_requests.updated_at,
-                    (SELECT ec.subject FROM email_contents ec WHERE ec.id = email_requests.content_id) as subject,
-                    (SELECT ec.content FROM email_contents ec WHERE ec.id = email_requests.content_id) as content
-                "#,
-                EmailStatus::Created as i16,
-                now,
-                self.config.scheduler.batch_size as i32,
-                EmailStatus::Processing as i16,
-                now
-            )
-            .fetch_all(&self.db)
-            .await?;
+            let (requests, throttled) = self.claim_batch(now).await?;
 
             if requests.is_empty() {
-                break;
+                // 예산이 없는 도메인에 대기 중인 요청이 있으면 충전될 때까지 기다린 뒤 계속
+                match throttled {
+                    Some(throttled)
+                        if self.has_pending_requests(now, &throttled.domains).await? =>
+                    {
+                        debug!(
+                            "📧 Waiting {:?} for throttled domains: {:?}",
+                            throttled.wait, throttled.domains
+                        );
+                        tokio::time::sleep(throttled.wait).await;
+                        continue;
+                    }
+                    _ => break,
+                }
             }
 
-            debug!("📧 Processing email batch of size: {}", requests.len());
+            debug!(
+                "📧 Processing email batch of size: {} (high priority: {})",
+                requests.len(),
+                requests
+                    .iter()
+                    .filter(|request| request.priority == MessagePriority::High)
+                    .count()
+            );
 
             // 제한된 동시성을 사용하여 요청을 병렬로 처리
-            let semaphore = Arc::new(tokio::sync::Semaphore::new(10)); // Max 10 concurrent
+            let semaphore = Arc::new(tokio::sync::Semaphore::new(
+                self.config.scheduler.publish_concurrency,
+            ));
             let mut tasks = Vec::new();
 
             for request in requests {
                 let producer = self.producer.clone();
                 let server_host = self.config.server.host.clone();
+                let signer = self.signer.clone();
+                let send_rate = self.send_rate.clone();
                 let permit = semaphore
                     .clone()
                     .acquire_owned()
@@ -128,8 +165,13 @@ impl SchedulerService {
 
                 let task = tokio::spawn(async move {
                     let _permit = permit; // Keep permit until task completes
-                    let result = producer.publish_email(&request, &server_host).await;
-                    (request.id, result)
+                    if let Some(send_rate) = send_rate {
+                        send_rate.acquire().await;
+                    }
+                    let result = producer
+                        .publish_email(&request, &server_host, &signer)
+                        .await;
+                    (request.id, request.attempts + 1, result)
                 });
 
                 tasks.push(task);
@@ -138,24 +180,47 @@ impl SchedulerService {
             // 결과 수집
             let mut updates = Vec::new();
             let mut success_count = 0;
+            let mut retry_count = 0;
 
             for task in tasks {
                 match task.await {
-                    Ok((request_id, Ok(_))) => {
+                    Ok((request_id, attempt, Ok(_))) => {
                         success_count += 1;
-                        updates.push((request_id, EmailStatus::Sent, None));
+                        updates.push(RequestUpdate::sent(request_id, attempt));
                     }
-                    Ok((request_id, Err(e))) => {
-                        warn!(
-                            "📧 Failed to publish email for request {}: {}",
-                            request_id, e
-                        );
-                        updates.push((request_id, EmailStatus::Failed, Some(e.to_string())));
+                    Ok((request_id, attempt, Err(e))) => {
+                        let retryable =
+                            e.is_transient() && attempt < self.config.scheduler.retry_max_attempts;
+
+                        if retryable {
+                            let delay = retry_delay(
+                                attempt,
+                                self.config.scheduler.retry_base_delay_secs,
+                                self.config.scheduler.retry_max_delay_secs,
+                            );
+                            warn!(
+                                "📧 Failed to publish email for request {} (attempt {}), retrying in {:?}: {}",
+                                request_id, attempt, delay, e
+                            );
+                            retry_count += 1;
+                            updates.push(RequestUpdate::retry(
+                                request_id,
+                                attempt,
+                                e.to_string(),
+                                Utc::now() + delay,
+                            ));
+                        } else {
+                            warn!(
+                                "📧 Failed to publish email for request {} (attempt {}): {}",
+                                request_id, attempt, e
+                            );
+                            updates.push(RequestUpdate::failed(request_id, attempt, e.to_string()));
+                        }
                     }
                     Err(e) => {
                         error!("📧 Task panicked: {}", e);
-                        // We can't identify the specific request, so we'll let the database
-                        // timeout handle the stuck "Processing" status
+                        // We can't identify the specific request, so we'll let the reaper
+                        // recover the stuck "Processing" status after the timeout
                     }
                 }
             }
@@ -172,9 +237,10 @@ impl SchedulerService {
             };
 
             info!(
-                "📧 Batch processed: success={}, failed={}, rate={:.1}%, duration={:?}",
+                "📧 Batch processed: success={}, retry={}, failed={}, rate={:.1}%, duration={:?}",
                 success_count,
-                batch_count - success_count,
+                retry_count,
+                batch_count - success_count - retry_count,
                 success_rate,
                 batch_start.elapsed()
             );
@@ -182,7 +248,8 @@ impl SchedulerService {
             total_processed += batch_count;
 
             // Small delay between batches to prevent overwhelming the system
-            if batch_count == self.config.scheduler.batch_size {
+            // (발행 속도 제한이 있으면 이미 속도가 조절되므로 생략)
+            if batch_count == self.config.scheduler.batch_size && self.send_rate.is_none() {
                 tokio::time::sleep(Duration::from_millis(100)).await;
             }
         }
@@ -190,10 +257,296 @@ impl SchedulerService {
         Ok(total_processed)
     }
 
-    async fn bulk_update_requests(
+    /// 발송할 요청을 가져오기 (도메인별 예산을 먼저 사용하고 나머지는 제한 없는 도메인에서)
+    ///
+    /// 예산이 없어 건너뛴 도메인이 있으면 해당 도메인과 다음 토큰이 충전될 때까지의 시간도 반환합니다.
+    async fn claim_batch(
+        &self,
+        now: DateTime<Utc>,
+    ) -> Result<(Vec<EmailRequestWithContent>, Option<ThrottledDomains>)> {
+        let scheduler = &self.config.scheduler;
+        let batch_size = claim_limit(
+            scheduler.batch_size,
+            scheduler.send_rate_per_sec,
+            scheduler.processing_timeout_secs,
+        );
+
+        // 도메인 그룹별로 지금 발행할 수 있는 요청 수와, 한 배치만큼(버킷 용량 이내) 충전될 때까지의 시간
+        // (토큰 하나만 기다리면 예산이 1일 때마다 깨어나 조회를 반복하게 됨)
+        let budgets: Vec<(u64, Duration)> = {
+            let mut buckets = self
+                .domain_buckets
+                .lock()
+                .unwrap_or_else(|e| e.into_inner());
+            let instant = Instant::now();
+            buckets
+                .iter_mut()
+                .map(|bucket| {
+                    let batch = bucket.capacity().min(batch_size as f64);
+                    (bucket.available_at(instant), bucket.until_available(batch))
+                })
+                .collect()
+        };
+
+        let mut requests = Vec::new();
+        let mut throttled_domains = Vec::new();
+        let mut throttled_wait: Option<Duration> = None;
+        let mut all_throttled_domains = Vec::new();
+
+        for (index, (limit, (budget, until_batch))) in self
+            .config
+            .scheduler
+            .domain_rate_limits
+            .iter()
+            .zip(budgets)
+            .enumerate()
+        {
+            all_throttled_domains.extend(limit.domains.iter().cloned());
+
+            if budget == 0 {
+                throttled_domains.extend(limit.domains.iter().cloned());
+                throttled_wait =
+                    Some(throttled_wait.map_or(until_batch, |wait| wait.min(until_batch)));
+                continue;
+            }
+            let remaining = batch_size - requests.len();
+            if remaining == 0 {
+                continue;
+            }
+
+            let claimed = self
+                .claim_prioritized(
+                    now,
+                    remaining.min(budget as usize),
+                    Some(&limit.domains),
+                    &[],
+                )
+                .await?;
+            if !claimed.is_empty() {
+                let mut buckets = self
+                    .domain_buckets
+                    .lock()
+                    .unwrap_or_else(|e| e.into_inner());
+                // 예산 안에서만 가져오므로 항상 성공
+                let _ = buckets[index].try_acquire_at(claimed.len() as f64, Instant::now());
+            }
+            requests.extend(claimed);
+        }
+
+        let remaining = batch_size - requests.len();
+        if remaining > 0 {
+            requests.extend(
+                self.claim_prioritized(now, remaining, None, &all_throttled_domains)
+                    .await?,
+            );
+        }
+
+        let throttled = throttled_wait.map(|wait| ThrottledDomains {
+            domains: throttled_domains,
+            wait,
+        });
+
+        Ok((requests, throttled))
+    }
+
+    /// 우선순위를 고려하여 최대 `limit`개의 요청 가져오기
+    ///
+    /// 높은 우선순위 요청을 예약된 몫만큼 먼저 가져오고 일반 요청으로 나머지를 채운 뒤,
+    /// 일반 요청이 부족해 남은 자리는 다시 높은 우선순위 요청으로 채웁니다.
+    async fn claim_prioritized(
+        &self,
+        now: DateTime<Utc>,
+        limit: usize,
+        include_domains: Option<&[String]>,
+        exclude_domains: &[String],
+    ) -> Result<Vec<EmailRequestWithContent>> {
+        let reserved = reserved_high_priority(limit, self.config.scheduler.high_priority_share);
+
+        let mut requests = Vec::with_capacity(limit);
+        if reserved > 0 {
+            requests.extend(
+                self.claim_requests(
+                    now,
+                    reserved,
+                    MessagePriority::High,
+                    include_domains,
+                    exclude_domains,
+                )
+                .await?,
+            );
+        }
+        // 예약된 몫을 다 채우지 못했다면 더 가져올 높은 우선순위 요청이 없음
+        let high_exhausted = requests.len() < reserved;
+
+        if requests.len() < limit {
+            requests.extend(
+                self.claim_requests(
+                    now,
+                    limit - requests.len(),
+                    MessagePriority::Normal,
+                    include_domains,
+                    exclude_domains,
+                )
+                .await?,
+            );
+        }
+        if requests.len() < limit && !high_exhausted {
+            requests.extend(
+                self.claim_requests(
+                    now,
+                    limit - requests.len(),
+                    MessagePriority::High,
+                    include_domains,
+                    exclude_domains,
+                )
+                .await?,
+            );
+        }
+
+        Ok(requests)
+    }
+
+    /// 발송 대상 요청을 Processing으로 바꾸며 가져오기
+    ///
+    /// `include_domains`가 있으면 해당 수신 도메인만, `exclude_domains`의 도메인은 제외합니다.
+    async fn claim_requests(
         &self,
-        updates: Vec<(uuid::Uuid, EmailStatus, Option<String>)>,
-    ) -> Result<()> {
+        now: DateTime<Utc>,
+        limit: usize,
+        priority: MessagePriority,
+        include_domains: Option<&[String]>,
+        exclude_domains: &[String],
+    ) -> Result<Vec<EmailRequestWithContent>> {
+        // FOR UPDATE SKIP LOCKED를 사용하여 요청을 원자적으로 가져오고 업데이트
+        let requests = sqlx::query_as!(
+            EmailRequestWithContent,
+            r#"
+            WITH locked_requests AS (
+                SELECT er.id
+                FROM email_requests er
+                WHERE er.status = $1 
+                  AND er.priority = $8
+                  AND (er.scheduled_at <= $2 OR er.scheduled_at IS NULL)
+                  AND (er.next_attempt_at <= $2 OR er.next_attempt_at IS NULL)
+                  AND NOT EXISTS (
+                      SELECT 1 FROM suppressions s
+                      WHERE s.tenant_id = er.tenant_id
+                        AND s.email = lower(er.to_email)
+                        AND (s.topic_id IS NULL OR s.topic_id = er.topic_id)
+                  )
+                  AND ($6::text[] IS NULL OR lower(split_part(er.to_email, '@', 2)) = ANY($6))
+                  AND NOT (lower(split_part(er.to_email, '@', 2)) = ANY($7))
+                ORDER BY 
+                    CASE WHEN er.scheduled_at IS NULL THEN 0 ELSE 1 END,
+                    er.scheduled_at ASC NULLS FIRST,
+                    er.created_at ASC
+                LIMIT $3
+                FOR UPDATE SKIP LOCKED
+            )
+            UPDATE email_requests
+            SET status = $4, updated_at = $5
+            FROM locked_requests lr, email_contents ec
+            LEFT JOIN email_template_versions tv ON tv.id = ec.template_version_id
+            WHERE email_requests.id = lr.id
+              AND ec.id = email_requests.content_id
+            RETURNING 
+                email_requests.id,
+                email_requests.topic_id,
+                email_requests.to_email,
+                email_requests.content_id,
+                email_requests.scheduled_at,
+                email_requests.status as "status: EmailStatus",
+                email_requests.priority as "priority: MessagePriority",
+                email_requests.error,
+                email_requests.attempts,
+                email_requests.vars,
+                email_requests.created_at,
+                email_requests.updated_at,
+                COALESCE(ec.subject, tv.subject) as subject,
+                COALESCE(ec.content, tv.content) as content,
+                ec.text_content,
+                ec.from_email,
+                ec.from_name,
+                ec.reply_to,
+                ec.cc,
+                ec.bcc,
+                ec.headers,
+                (SELECT COALESCE(jsonb_agg(jsonb_build_object(
+                    'id', a.id, 'filename', a.filename, 'content_type', a.content_type,
+                    'cid', a.cid, 'size', a.size) ORDER BY a.id), '[]'::jsonb)
+                 FROM email_attachments a WHERE a.content_id = ec.id) as "attachments!"
+            "#,
+            EmailStatus::Created as i16,
+            now,
+            limit as i32,
+            EmailStatus::Processing as i16,
+            now,
+            include_domains,
+            exclude_domains,
+            priority as i16
+        )
+        .fetch_all(&self.db)
+        .await?;
+
+        Ok(requests)
+    }
+
+    /// 주어진 수신 도메인에 발송 시각이 된 요청이 남아 있는지 여부
+    async fn has_pending_requests(&self, now: DateTime<Utc>, domains: &[String]) -> Result<bool> {
+        let pending = sqlx::query_scalar!(
+            r#"
+            SELECT EXISTS(
+                SELECT 1 FROM email_requests er
+                WHERE er.status = $1
+                  AND (er.scheduled_at <= $2 OR er.scheduled_at IS NULL)
+                  AND (er.next_attempt_at <= $2 OR er.next_attempt_at IS NULL)
+                  AND lower(split_part(er.to_email, '@', 2)) = ANY($3)
+            ) as "pending!"
+            "#,
+            EmailStatus::Created as i16,
+            now,
+            domains
+        )
+        .fetch_one(&self.db)
+        .await?;
+
+        Ok(pending)
+    }
+
+    /// 발송 시각이 된 요청 중 수신 거부된 수신자의 요청을 사유와 함께 중지
+    async fn stop_suppressed_requests(&self, now: DateTime<Utc>) -> Result<u64> {
+        let stopped = sqlx::query!(
+            r#"
+            UPDATE email_requests er
+            SET status = $1,
+                error = 'Recipient suppressed: ' || s.reason,
+                updated_at = $2
+            FROM suppressions s
+            WHERE er.status = $3
+              AND (er.scheduled_at <= $2 OR er.scheduled_at IS NULL)
+              AND s.tenant_id = er.tenant_id
+              AND s.email = lower(er.to_email)
+              AND (s.topic_id IS NULL OR s.topic_id = er.topic_id)
+            "#,
+            EmailStatus::Stopped as i16,
+            now,
+            EmailStatus::Created as i16
+        )
+        .execute(&self.db)
+        .await?
+        .rows_affected();
+
+        if stopped > 0 {
+            info!(
+                "🚫 Stopped {} email requests for suppressed recipients",
+                stopped
+            );
+        }
+
+        Ok(stopped)
+    }
+
+    async fn bulk_update_requests(&self, updates: Vec<RequestUpdate>) -> Result<()> {
         if updates.is_empty() {
             return Ok(());
         }
@@ -205,13 +558,33 @@ impl SchedulerService {
         let now = Utc::now();
         let mut success_count = 0;
 
-        for (id, status, error) in updates {
+        for update in updates {
+            // 처리 중 API로 중지된 요청은 덮어쓰지 않음
+            // 시도 결과는 attempt_history에 누적하여 요청 조회 시 확인 가능
             let rows_affected = sqlx::query!(
-                "UPDATE email_requests SET status = $1, error = $2, updated_at = $3 WHERE id = $4",
-                status as i16,
-                error,
+                r#"
+                UPDATE email_requests
+                SET status = $1,
+                    error = $2::text,
+                    attempts = $3,
+                    next_attempt_at = $4,
+                    attempt_history = attempt_history || jsonb_build_array(jsonb_build_object(
+                        'attempt', $3::smallint,
+                        'outcome', $5::text,
+                        'error', $2::text,
+                        'at', $6::timestamptz
+                    )),
+                    updated_at = $6
+                WHERE id = $7 AND status = $8
+                "#,
+                update.status as i16,
+                update.error,
+                update.attempt,
+                update.next_attempt_at,
+                update.outcome,
                 now,
-                id
+                update.id,
+                EmailStatus::Processing as i16
             )
             .execute(&mut *tx)
             .await?
@@ -220,7 +593,10 @@ impl SchedulerService {
             if rows_affected > 0 {
                 success_count += 1;
             } else {
-                warn!("📧 Failed to update request {}: not found", id);
+                warn!(
+                    "📧 Failed to update request {}: not found or no longer processing",
+                    update.id
+                );
             }
         }
 
@@ -267,6 +643,98 @@ impl SchedulerService {
     }
 }
 
+/// 예산이 없어 이번 배치에서 건너뛴 수신 도메인
+struct ThrottledDomains {
+    domains: Vec<String>,
+    /// 가장 먼저 한 배치만큼의 토큰이 충전되는 그룹까지의 시간
+    wait: Duration,
+}
+
+/// 발행 시도 결과에 따른 요청 상태 변경
+struct RequestUpdate {
+    id: Uuid,
+    status: EmailStatus,
+    error: Option<String>,
+    attempt: i16,
+    next_attempt_at: Option<DateTime<Utc>>,
+    outcome: &'static str,
+}
+
+impl RequestUpdate {
+    fn sent(id: Uuid, attempt: i16) -> Self {
+        Self {
+            id,
+            status: EmailStatus::Sent,
+            error: None,
+            attempt,
+            next_attempt_at: None,
+            outcome: "sent",
+        }
+    }
+
+    fn retry(id: Uuid, attempt: i16, error: String, next_attempt_at: DateTime<Utc>) -> Self {
+        Self {
+            id,
+            status: EmailStatus::Created,
+            error: Some(truncate_error(error)),
+            attempt,
+            next_attempt_at: Some(next_attempt_at),
+            outcome: "retry",
+        }
+    }
+
+    fn failed(id: Uuid, attempt: i16, error: String) -> Self {
+        Self {
+            id,
+            status: EmailStatus::Failed,
+            error: Some(truncate_error(error)),
+            attempt,
+            next_attempt_at: None,
+            outcome: "failed",
+        }
+    }
+}
+
+fn truncate_error(error: String) -> String {
+    if error.len() <= MAX_ERROR_LENGTH {
+        return error;
+    }
+    let mut end = MAX_ERROR_LENGTH;
+    while !error.is_char_boundary(end) {
+        end -= 1;
+    }
+    error[..end].to_string()
+}
+
+/// 한 번에 가져올 요청 수
+///
+/// 가져온 요청은 발행이 끝날 때까지 Processing 상태이므로, 발행 속도 제한이 있으면
+/// 복구 작업이 같은 요청을 다시 발행하지 않도록 타임아웃의 절반 안에 발행할 수 있는 수로 제한합니다.
+fn claim_limit(batch_size: usize, send_rate_per_sec: f64, processing_timeout_secs: u64) -> usize {
+    if send_rate_per_sec <= 0.0 {
+        return batch_size;
+    }
+    let within_timeout = (send_rate_per_sec * processing_timeout_secs as f64 / 2.0).floor();
+    batch_size.min((within_timeout as usize).max(1))
+}
+
+/// 배치 크기 `limit` 중 높은 우선순위 요청에 먼저 배정할 수 (최소 1개, 비율이 0이면 0개)
+fn reserved_high_priority(limit: usize, share: f64) -> usize {
+    if share <= 0.0 || limit == 0 {
+        return 0;
+    }
+    ((limit as f64 * share).ceil() as usize).clamp(1, limit)
+}
+
+/// 지수 백오프 재시도 지연 시간 (attempt는 1부터 시작, 최대 지연 시간으로 제한)
+pub fn retry_delay(attempt: i16, base_delay_secs: u64, max_delay_secs: u64) -> chrono::Duration {
+    let exponent = (attempt.max(1) - 1).min(32) as u32;
+    let delay = base_delay_secs
+        .saturating_mul(2u64.saturating_pow(exponent))
+        .min(max_delay_secs);
+    chrono::Duration::seconds(delay as i64)
+}
+
 #[derive(Debug, Default)]
 #[allow(dead_code)]
 pub struct SchedulerStats {
@@ -293,3 +761,60 @@ impl SchedulerStats {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_retry_delay_exponential_backoff() {
+        // 기본 지연 시간에서 시도마다 두 배씩 증가
+        assert_eq!(retry_delay(1, 30, 3600), chrono::Duration::seconds(30));
+        assert_eq!(retry_delay(2, 30, 3600), chrono::Duration::seconds(60));
+        assert_eq!(retry_delay(3, 30, 3600), chrono::Duration::seconds(120));
+        assert_eq!(retry_delay(4, 30, 3600), chrono::Duration::seconds(240));
+    }
+
+    #[test]
+    fn test_retry_delay_capped() {
+        // 최대 지연 시간을 넘지 않아야 함
+        assert_eq!(retry_delay(10, 30, 3600), chrono::Duration::seconds(3600));
+        assert_eq!(
+            retry_delay(i16::MAX, 30, 3600),
+            chrono::Duration::seconds(3600)
+        );
+    }
+
+    #[test]
+    fn test_claim_limit() {
+        // 제한이 없으면 배치 크기 그대로
+        assert_eq!(claim_limit(1000, 0.0, 600), 1000);
+        assert_eq!(claim_limit(1000, 14.0, 600), 1000);
+        // 초당 1건이면 타임아웃(600초)의 절반인 300건까지
+        assert_eq!(claim_limit(1000, 1.0, 600), 300);
+        // 아무리 느려도 최소 1건
+        assert_eq!(claim_limit(1000, 0.001, 600), 1);
+    }
+
+    #[test]
+    fn test_reserved_high_priority() {
+        assert_eq!(reserved_high_priority(1000, 0.5), 500);
+        assert_eq!(reserved_high_priority(1000, 1.0), 1000);
+        assert_eq!(reserved_high_priority(1000, 0.0), 0);
+        // 작은 배치에서도 최소 한 자리는 높은 우선순위에 배정
+        assert_eq!(reserved_high_priority(3, 0.1), 1);
+        assert_eq!(reserved_high_priority(0, 0.5), 0);
+    }
+
+    #[test]
+    fn test_truncate_error() {
+        // 짧은 오류 메시지는 그대로 유지
+        assert_eq!(truncate_error("timeout".to_string()), "timeout");
+
+        // 멀티바이트 문자 경계를 지키며 255바이트 이하로 자름
+        let long = "가".repeat(200);
+        let truncated = truncate_error(long);
+        assert!(truncated.len() <= MAX_ERROR_LENGTH);
+        assert!(truncated.chars().all(|c| c == '가'));
+    }
+}
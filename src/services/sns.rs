@@ -0,0 +1,435 @@
+use crate::{config::SnsConfig, dto::SnsMessage};
+use base64::{engine::general_purpose::STANDARD, Engine};
+use regex::Regex;
+use reqwest::Url;
+use ring::signature::{self, UnparsedPublicKey, VerificationAlgorithm};
+use std::{
+    collections::HashMap,
+    sync::{Arc, RwLock},
+    time::Duration,
+};
+use thiserror::Error;
+use tracing::info;
+use x509_cert::{der::DecodePem, Certificate};
+
+/// 캐시에 보관할 최대 인증서 수 (초과 시 비우고 다시 가져옴)
+const MAX_CACHED_CERTIFICATES: usize = 64;
+
+lazy_static::lazy_static! {
+    static ref SUBSCRIPTION_ARN_REGEX: Regex =
+        Regex::new(r"<SubscriptionArn>\s*([^<\s]+)\s*</SubscriptionArn>").unwrap();
+}
+
+#[derive(Debug, Error)]
+pub enum SnsVerificationError {
+    #[error("Missing field: {0}")]
+    MissingField(&'static str),
+
+    #[error("Unsupported message type: {0}")]
+    UnsupportedMessageType(String),
+
+    #[error("Unsupported signature version: {0}")]
+    UnsupportedSignatureVersion(String),
+
+    #[error("Signing certificate URL not allowed: {0}")]
+    CertificateUrlNotAllowed(String),
+
+    #[error("Topic not allowed: {0}")]
+    TopicNotAllowed(String),
+
+    #[error("Failed to fetch signing certificate: {0}")]
+    CertificateFetch(String),
+
+    #[error("Invalid signing certificate: {0}")]
+    InvalidCertificate(String),
+
+    #[error("Invalid signature")]
+    InvalidSignature,
+
+    #[error("Failed to confirm subscription: {0}")]
+    SubscriptionConfirmation(String),
+}
+
+/// SNS 메시지 서명 검증기
+///
+/// 서명 인증서는 허용된 호스트에서만 HTTPS로 가져오며, URL별로 공개 키를 캐시합니다.
+pub struct SnsVerifier {
+    allowed_cert_hosts: Vec<String>,
+    allowed_topic_arns: Vec<String>,
+    auto_confirm_subscriptions: bool,
+    client: reqwest::Client,
+    public_keys: RwLock<HashMap<String, Arc<Vec<u8>>>>,
+}
+
+impl SnsVerifier {
+    pub fn new(config: &SnsConfig) -> Self {
+        Self {
+            allowed_cert_hosts: config.allowed_cert_hosts.clone(),
+            allowed_topic_arns: config.allowed_topic_arns.clone(),
+            auto_confirm_subscriptions: config.auto_confirm_subscriptions,
+            client: reqwest::Client::builder()
+                .timeout(Duration::from_secs(10))
+                .build()
+                .expect("Failed to build HTTP client"),
+            public_keys: RwLock::new(HashMap::new()),
+        }
+    }
+
+    pub async fn verify(&self, message: &SnsMessage) -> Result<(), SnsVerificationError> {
+        let topic_arn = required(&message.topic_arn, "TopicArn")?;
+        if !self.is_topic_allowed(topic_arn) {
+            return Err(SnsVerificationError::TopicNotAllowed(topic_arn.to_string()));
+        }
+
+        let cert_url = required(&message.signing_cert_url, "SigningCertURL")?;
+        let cert_url = certificate_url(cert_url, &self.allowed_cert_hosts)?;
+        let public_key = self.public_key(cert_url).await?;
+
+        verify_signature(message, &public_key)
+    }
+
+    /// 허용 목록이 비어 있으면 모든 토픽 허용
+    pub fn is_topic_allowed(&self, topic_arn: &str) -> bool {
+        self.allowed_topic_arns.is_empty()
+            || self.allowed_topic_arns.iter().any(|arn| arn == topic_arn)
+    }
+
+    /// 자동 구독 확인 대상 여부 (허용 목록에 명시된 토픽만)
+    pub fn should_auto_confirm(&self, topic_arn: &str) -> bool {
+        self.auto_confirm_subscriptions
+            && self.allowed_topic_arns.iter().any(|arn| arn == topic_arn)
+    }
+
+    /// SubscribeURL을 호출하여 구독을 확인하고 구독 ARN 반환
+    pub async fn confirm_subscription(
+        &self,
+        message: &SnsMessage,
+    ) -> Result<String, SnsVerificationError> {
+        let subscribe_url = required(&message.subscribe_url, "SubscribeURL")?;
+        let url = subscription_url(subscribe_url, &self.allowed_cert_hosts)?;
+
+        let body = self
+            .client
+            .get(url)
+            .send()
+            .await
+            .and_then(|response| response.error_for_status())
+            .map_err(|e| SnsVerificationError::SubscriptionConfirmation(e.to_string()))?
+            .text()
+            .await
+            .map_err(|e| SnsVerificationError::SubscriptionConfirmation(e.to_string()))?;
+
+        subscription_arn(&body).ok_or_else(|| {
+            SnsVerificationError::SubscriptionConfirmation(
+                "SubscriptionArn not found in response".to_string(),
+            )
+        })
+    }
+
+    async fn public_key(&self, url: Url) -> Result<Arc<Vec<u8>>, SnsVerificationError> {
+        if let Some(key) = self.public_keys.read().unwrap().get(url.as_str()) {
+            return Ok(key.clone());
+        }
+
+        let pem = self
+            .client
+            .get(url.clone())
+            .send()
+            .await
+            .and_then(|response| response.error_for_status())
+            .map_err(|e| SnsVerificationError::CertificateFetch(e.to_string()))?
+            .text()
+            .await
+            .map_err(|e| SnsVerificationError::CertificateFetch(e.to_string()))?;
+        let key = Arc::new(public_key_from_pem(&pem)?);
+
+        info!("🔒 SNS signing certificate cached: {}", url);
+
+        let mut public_keys = self.public_keys.write().unwrap();
+        if public_keys.len() >= MAX_CACHED_CERTIFICATES {
+            public_keys.clear();
+        }
+        public_keys.insert(url.to_string(), key.clone());
+
+        Ok(key)
+    }
+}
+
+fn required<'a>(
+    value: &'a Option<String>,
+    name: &'static str,
+) -> Result<&'a str, SnsVerificationError> {
+    value
+        .as_deref()
+        .ok_or(SnsVerificationError::MissingField(name))
+}
+
+/// 서명 인증서 URL 검증 (HTTPS, 허용된 호스트, .pem 파일)
+fn certificate_url(url: &str, allowed_hosts: &[String]) -> Result<Url, SnsVerificationError> {
+    let not_allowed = || SnsVerificationError::CertificateUrlNotAllowed(url.to_string());
+    let parsed = Url::parse(url).map_err(|_| not_allowed())?;
+
+    let host = parsed.host_str().ok_or_else(not_allowed)?;
+    let host_allowed = allowed_hosts
+        .iter()
+        .any(|pattern| host_matches(pattern, host));
+
+    if parsed.scheme() != "https" || !host_allowed || !parsed.path().ends_with(".pem") {
+        return Err(not_allowed());
+    }
+    Ok(parsed)
+}
+
+/// 구독 확인 URL 검증 (HTTPS, 허용된 SNS 호스트의 ConfirmSubscription 호출)
+fn subscription_url(url: &str, allowed_hosts: &[String]) -> Result<Url, SnsVerificationError> {
+    let not_allowed =
+        || SnsVerificationError::SubscriptionConfirmation(format!("URL not allowed: {}", url));
+    let parsed = Url::parse(url).map_err(|_| not_allowed())?;
+
+    let host = parsed.host_str().ok_or_else(not_allowed)?;
+    let host_allowed = allowed_hosts
+        .iter()
+        .any(|pattern| host_matches(pattern, host));
+    let is_confirm_action = parsed
+        .query_pairs()
+        .any(|(key, value)| key == "Action" && value == "ConfirmSubscription");
+
+    if parsed.scheme() != "https" || !host_allowed || !is_confirm_action {
+        return Err(not_allowed());
+    }
+    Ok(parsed)
+}
+
+/// ConfirmSubscription 응답(XML)에서 구독 ARN 추출
+fn subscription_arn(body: &str) -> Option<String> {
+    SUBSCRIPTION_ARN_REGEX
+        .captures(body)
+        .map(|caps| caps[1].to_string())
+}
+
+/// 호스트 패턴 비교 (`*`는 라벨 하나와 일치, 예: `sns.*.amazonaws.com`)
+fn host_matches(pattern: &str, host: &str) -> bool {
+    let pattern: Vec<&str> = pattern.split('.').collect();
+    let host: Vec<&str> = host.split('.').collect();
+
+    pattern.len() == host.len()
+        && pattern
+            .iter()
+            .zip(&host)
+            .all(|(p, h)| *p == "*" || p.eq_ignore_ascii_case(h))
+}
+
+/// PEM 인증서에서 RSA 공개 키(DER) 추출
+fn public_key_from_pem(pem: &str) -> Result<Vec<u8>, SnsVerificationError> {
+    let certificate = Certificate::from_pem(pem)
+        .map_err(|e| SnsVerificationError::InvalidCertificate(e.to_string()))?;
+
+    Ok(certificate
+        .tbs_certificate
+        .subject_public_key_info
+        .subject_public_key
+        .raw_bytes()
+        .to_vec())
+}
+
+/// SNS 서명 대상 문자열 (메시지 유형별로 정해진 필드를 이름 순으로 나열)
+fn string_to_sign(message: &SnsMessage) -> Result<String, SnsVerificationError> {
+    let fields: Vec<(&str, Option<&str>, bool)> = match message.message_type.as_str() {
+        "Notification" => vec![
+            ("Message", Some(message.message.as_str()), true),
+            ("MessageId", Some(message.message_id.as_str()), true),
+            ("Subject", message.subject.as_deref(), false),
+            ("Timestamp", message.timestamp.as_deref(), true),
+            ("TopicArn", message.topic_arn.as_deref(), true),
+            ("Type", Some(message.message_type.as_str()), true),
+        ],
+        "SubscriptionConfirmation" | "UnsubscribeConfirmation" => vec![
+            ("Message", Some(message.message.as_str()), true),
+            ("MessageId", Some(message.message_id.as_str()), true),
+            ("SubscribeURL", message.subscribe_url.as_deref(), true),
+            ("Timestamp", message.timestamp.as_deref(), true),
+            ("Token", message.token.as_deref(), true),
+            ("TopicArn", message.topic_arn.as_deref(), true),
+            ("Type", Some(message.message_type.as_str()), true),
+        ],
+        other => {
+            return Err(SnsVerificationError::UnsupportedMessageType(
+                other.to_string(),
+            ))
+        }
+    };
+
+    let mut result = String::new();
+    for (name, value, is_required) in fields {
+        match value {
+            Some(value) => {
+                result.push_str(name);
+                result.push('\n');
+                result.push_str(value);
+                result.push('\n');
+            }
+            None if is_required => return Err(SnsVerificationError::MissingField(name)),
+            None => {}
+        }
+    }
+    Ok(result)
+}
+
+/// SignatureVersion 1(SHA1withRSA)과 2(SHA256withRSA) 서명 검증
+fn verify_signature(message: &SnsMessage, public_key: &[u8]) -> Result<(), SnsVerificationError> {
+    let algorithm: &'static dyn VerificationAlgorithm =
+        match required(&message.signature_version, "SignatureVersion")? {
+            "1" => &signature::RSA_PKCS1_2048_8192_SHA1_FOR_LEGACY_USE_ONLY,
+            "2" => &signature::RSA_PKCS1_2048_8192_SHA256,
+            other => {
+                return Err(SnsVerificationError::UnsupportedSignatureVersion(
+                    other.to_string(),
+                ))
+            }
+        };
+
+    let signature = STANDARD
+        .decode(required(&message.signature, "Signature")?)
+        .map_err(|_| SnsVerificationError::InvalidSignature)?;
+
+    UnparsedPublicKey::new(algorithm, public_key)
+        .verify(string_to_sign(message)?.as_bytes(), &signature)
+        .map_err(|_| SnsVerificationError::InvalidSignature)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 테스트용 자체 서명 인증서와, 아래 notification()의 서명 대상 문자열에 대한 서명
+    const TEST_CERTIFICATE: &str = include_str!("testdata/sns_signing_cert.pem");
+    const SIGNATURE_V1: &str = "EjmptHYnrzTrfMOcBpW0zmb9D/Ie2OgceQyrNyfcEbZb8el0dsDyFk2qGSE3fAKULTbuaNKO6VdBS0cy09TpCYkYy9tb5ryMCBzTiiVb0B/XENQZx5+PUlGMlN+A1hP6oeuDuEaMIWPtxEfaZ0bqDr9LBr8KkCnn9oqZx2zLLXjWqLxD5vFJWnhhSq7axSTLP6rg+dnPhKTuc7BUCGgvSzm9gyjHybA8if1vy+BZEeXYv/UPMYaWBMIhb7YsVtW3CyDEc5F8cBFb4fs61BXVN5pNZTl1X4HTyXqvM8ODqrkmkSg5lsEYbUk5AXk6bsa3oLaov4g3Wufnn5TD8GZb7A==";
+    const SIGNATURE_V2: &str = "bAgR3OUs6XR/9GmtguZVGCN7Mec+IpC5QiDRMeXKyhpz5zJfnamjCdgeI7BSga7sxQeCbGmQj1n84WSu769J33OgYem2zbjDQYoj3Jl47AoRWD80WLji2dWXJ+WwHceVNSHw/TLJmI8mL8+59vxuPq18Ay9F7/Bb4RXazYPL2p23F1Qvc1rN+iLNDR03AjL0ZRW9jWk5uYKx2fJiLudSK3pvFqgjWh9++4zG+PJD8e1PUOs6/vh/+XWeRM1O8fxXZGt0M6wijxnoZnoUY9WYKTFaAsM2EWzWcMzRhDk7C0XdkOQVJHXCHAlVLOoie7DbT8X7MD28JHE0T2Fc9903Ww==";
+
+    fn notification(signature_version: &str, signature: &str) -> SnsMessage {
+        SnsMessage {
+            message_type: "Notification".to_string(),
+            message: "hello".to_string(),
+            message_id: "msg-1".to_string(),
+            subject: None,
+            timestamp: Some("2024-01-01T00:00:00.000Z".to_string()),
+            topic_arn: Some("arn:aws:sns:us-east-1:123456789012:ses-events".to_string()),
+            subscribe_url: None,
+            token: None,
+            signature_version: Some(signature_version.to_string()),
+            signature: Some(signature.to_string()),
+            signing_cert_url: None,
+        }
+    }
+
+    #[test]
+    fn test_string_to_sign() {
+        let message = notification("1", SIGNATURE_V1);
+        assert_eq!(
+            string_to_sign(&message).unwrap(),
+            "Message\nhello\nMessageId\nmsg-1\nTimestamp\n2024-01-01T00:00:00.000Z\n\
+             TopicArn\narn:aws:sns:us-east-1:123456789012:ses-events\nType\nNotification\n"
+        );
+
+        // 구독 확인 메시지는 SubscribeURL, Token이 필수
+        let mut confirmation = notification("1", SIGNATURE_V1);
+        confirmation.message_type = "SubscriptionConfirmation".to_string();
+        assert!(matches!(
+            string_to_sign(&confirmation),
+            Err(SnsVerificationError::MissingField("SubscribeURL"))
+        ));
+    }
+
+    #[test]
+    fn test_verify_signature_versions() {
+        let public_key = public_key_from_pem(TEST_CERTIFICATE).unwrap();
+
+        assert!(verify_signature(&notification("1", SIGNATURE_V1), &public_key).is_ok());
+        assert!(verify_signature(&notification("2", SIGNATURE_V2), &public_key).is_ok());
+
+        // 버전과 서명 알고리즘이 맞지 않거나 내용이 바뀌면 거부
+        assert!(matches!(
+            verify_signature(&notification("2", SIGNATURE_V1), &public_key),
+            Err(SnsVerificationError::InvalidSignature)
+        ));
+        let mut tampered = notification("2", SIGNATURE_V2);
+        tampered.message = "bounce".to_string();
+        assert!(matches!(
+            verify_signature(&tampered, &public_key),
+            Err(SnsVerificationError::InvalidSignature)
+        ));
+        assert!(matches!(
+            verify_signature(&notification("3", SIGNATURE_V2), &public_key),
+            Err(SnsVerificationError::UnsupportedSignatureVersion(_))
+        ));
+    }
+
+    #[test]
+    fn test_certificate_url() {
+        let allowed = vec!["sns.*.amazonaws.com".to_string()];
+
+        assert!(certificate_url(
+            "https://sns.us-east-1.amazonaws.com/SimpleNotificationService-abc.pem",
+            &allowed
+        )
+        .is_ok());
+        for url in [
+            "http://sns.us-east-1.amazonaws.com/SimpleNotificationService-abc.pem",
+            "https://sns.us-east-1.amazonaws.com.evil.com/SimpleNotificationService-abc.pem",
+            "https://evil.com/sns.us-east-1.amazonaws.com/cert.pem",
+            "https://sns.us-east-1.amazonaws.com/cert.txt",
+            "not a url",
+        ] {
+            assert!(certificate_url(url, &allowed).is_err(), "{}", url);
+        }
+    }
+
+    #[test]
+    fn test_subscription_url() {
+        let allowed = vec!["sns.*.amazonaws.com".to_string()];
+
+        assert!(subscription_url(
+            "https://sns.us-east-1.amazonaws.com/?Action=ConfirmSubscription&TopicArn=arn:aws:sns:us-east-1:123456789012:ses-events&Token=abc",
+            &allowed
+        )
+        .is_ok());
+        for url in [
+            "https://example.com/?Action=ConfirmSubscription&Token=abc",
+            "http://sns.us-east-1.amazonaws.com/?Action=ConfirmSubscription&Token=abc",
+            "https://sns.us-east-1.amazonaws.com/?Action=Unsubscribe",
+        ] {
+            assert!(subscription_url(url, &allowed).is_err(), "{}", url);
+        }
+    }
+
+    #[test]
+    fn test_subscription_arn() {
+        let body = r#"<ConfirmSubscriptionResponse xmlns="http://sns.amazonaws.com/doc/2010-03-31/">
+  <ConfirmSubscriptionResult>
+    <SubscriptionArn>arn:aws:sns:us-east-1:123456789012:ses-events:2bcfbf39-05c3-41de-beaa-fcfcc21c8f55</SubscriptionArn>
+  </ConfirmSubscriptionResult>
+</ConfirmSubscriptionResponse>"#;
+        assert_eq!(
+            subscription_arn(body).as_deref(),
+            Some("arn:aws:sns:us-east-1:123456789012:ses-events:2bcfbf39-05c3-41de-beaa-fcfcc21c8f55")
+        );
+        assert_eq!(subscription_arn("<Error/>"), None);
+    }
+
+    #[test]
+    fn test_host_matches() {
+        assert!(host_matches(
+            "sns.*.amazonaws.com",
+            "sns.eu-west-1.amazonaws.com"
+        ));
+        assert!(host_matches(
+            "sns.*.amazonaws.com",
+            "SNS.EU-WEST-1.AMAZONAWS.COM"
+        ));
+        assert!(!host_matches("sns.*.amazonaws.com", "sns.amazonaws.com"));
+        assert!(!host_matches(
+            "sns.*.amazonaws.com",
+            "sns.a.b.amazonaws.com"
+        ));
+    }
+}
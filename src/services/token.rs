@@ -0,0 +1,197 @@
+use crate::config::SecurityConfig;
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
+use chrono::{DateTime, Utc};
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+use thiserror::Error;
+
+type HmacSha256 = Hmac<Sha256>;
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum TokenError {
+    #[error("Malformed token")]
+    Malformed,
+    #[error("Token expired")]
+    Expired,
+    #[error("Invalid token signature")]
+    InvalidSignature,
+}
+
+/// 트래킹 URL 등 외부에 노출되는 링크용 HMAC 서명 토큰
+///
+/// 토큰 형식은 `{만료 시각(unix 초, 0이면 만료 없음)}.{base64url 서명}`이며,
+/// 서명은 용도(`purpose`)와 대상(`subject`)에 묶여 있어 다른 링크에 재사용할 수 없습니다.
+/// 새 토큰은 현재 키로 서명하고, 검증은 이전 키까지 허용하여 키 교체 후에도 기존 메일의 링크가 동작합니다.
+#[derive(Clone)]
+pub struct TokenSigner {
+    current: Vec<u8>,
+    previous: Vec<Vec<u8>>,
+    ttl: Option<chrono::Duration>,
+}
+
+impl TokenSigner {
+    pub fn new(config: &SecurityConfig) -> Self {
+        Self {
+            current: config.tracking_secret.as_bytes().to_vec(),
+            previous: config
+                .tracking_previous_secrets
+                .iter()
+                .map(|secret| secret.as_bytes().to_vec())
+                .collect(),
+            ttl: match config.tracking_token_ttl_secs {
+                0 => None,
+                secs => Some(chrono::Duration::seconds(secs as i64)),
+            },
+        }
+    }
+
+    pub fn sign(&self, purpose: &str, subject: &str) -> String {
+        self.sign_at(purpose, subject, Utc::now())
+    }
+
+    /// 만료되면 안 되는 링크(수신 거부 등)용 토큰 (TTL 설정과 무관하게 만료 없음)
+    pub fn sign_without_expiry(&self, purpose: &str, subject: &str) -> String {
+        self.sign_expiring_at(purpose, subject, 0)
+    }
+
+    fn sign_at(&self, purpose: &str, subject: &str, now: DateTime<Utc>) -> String {
+        let expires_at = self.ttl.map_or(0, |ttl| (now + ttl).timestamp());
+        self.sign_expiring_at(purpose, subject, expires_at)
+    }
+
+    fn sign_expiring_at(&self, purpose: &str, subject: &str, expires_at: i64) -> String {
+        let signature = mac(&self.current, purpose, subject, expires_at).finalize();
+        format!(
+            "{}.{}",
+            expires_at,
+            URL_SAFE_NO_PAD.encode(signature.into_bytes())
+        )
+    }
+
+    pub fn verify(&self, purpose: &str, subject: &str, token: &str) -> Result<(), TokenError> {
+        self.verify_at(purpose, subject, token, Utc::now())
+    }
+
+    fn verify_at(
+        &self,
+        purpose: &str,
+        subject: &str,
+        token: &str,
+        now: DateTime<Utc>,
+    ) -> Result<(), TokenError> {
+        let (expires_at, signature) = token.split_once('.').ok_or(TokenError::Malformed)?;
+        let expires_at: i64 = expires_at.parse().map_err(|_| TokenError::Malformed)?;
+        let signature = URL_SAFE_NO_PAD
+            .decode(signature)
+            .map_err(|_| TokenError::Malformed)?;
+
+        // 서명 비교는 상수 시간으로 수행
+        let valid = std::iter::once(&self.current)
+            .chain(&self.previous)
+            .any(|key| {
+                mac(key, purpose, subject, expires_at)
+                    .verify_slice(&signature)
+                    .is_ok()
+            });
+        if !valid {
+            return Err(TokenError::InvalidSignature);
+        }
+
+        if expires_at != 0 && expires_at < now.timestamp() {
+            return Err(TokenError::Expired);
+        }
+
+        Ok(())
+    }
+}
+
+fn mac(key: &[u8], purpose: &str, subject: &str, expires_at: i64) -> HmacSha256 {
+    let mut mac = HmacSha256::new_from_slice(key).expect("HMAC accepts keys of any length");
+    mac.update(format!("{}\n{}\n{}", purpose, subject, expires_at).as_bytes());
+    mac
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn signer(current: &str, previous: &[&str], ttl_secs: u64) -> TokenSigner {
+        TokenSigner::new(&SecurityConfig {
+            api_key: None,
+            tracking_secret: current.to_string(),
+            tracking_previous_secrets: previous.iter().map(|s| s.to_string()).collect(),
+            tracking_token_ttl_secs: ttl_secs,
+        })
+    }
+
+    #[test]
+    fn test_sign_and_verify() {
+        let signer = signer("secret", &[], 0);
+        let token = signer.sign("open", "request-1");
+
+        assert!(token.starts_with("0."));
+        assert_eq!(signer.verify("open", "request-1", &token), Ok(()));
+        // 다른 대상이나 용도에는 재사용 불가
+        assert_eq!(
+            signer.verify("open", "request-2", &token),
+            Err(TokenError::InvalidSignature)
+        );
+        assert_eq!(
+            signer.verify("click", "request-1", &token),
+            Err(TokenError::InvalidSignature)
+        );
+        assert_eq!(
+            signer.verify("open", "request-1", "garbage"),
+            Err(TokenError::Malformed)
+        );
+    }
+
+    #[test]
+    fn test_expiry() {
+        let signer = signer("secret", &[], 60);
+        let now = Utc::now();
+        let token = signer.sign_at("open", "request-1", now);
+
+        assert_eq!(signer.verify_at("open", "request-1", &token, now), Ok(()));
+        assert_eq!(
+            signer.verify_at(
+                "open",
+                "request-1",
+                &token,
+                now + chrono::Duration::seconds(61)
+            ),
+            Err(TokenError::Expired)
+        );
+        // 만료 시각을 조작하면 서명이 맞지 않음
+        let (_, signature) = token.split_once('.').unwrap();
+        assert_eq!(
+            signer.verify_at("open", "request-1", &format!("0.{}", signature), now),
+            Err(TokenError::InvalidSignature)
+        );
+
+        // 만료 없는 토큰은 TTL과 무관하게 유효
+        let token = signer.sign_without_expiry("unsubscribe", "request-1");
+        assert_eq!(
+            signer.verify_at(
+                "unsubscribe",
+                "request-1",
+                &token,
+                now + chrono::Duration::days(3650)
+            ),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn test_key_rotation() {
+        // 이전 키로 서명된 토큰은 교체 후에도 검증 가능
+        let old_token = signer("old", &[], 0).sign("open", "request-1");
+        let rotated = signer("new", &["old"], 0);
+
+        assert_eq!(rotated.verify("open", "request-1", &old_token), Ok(()));
+        assert_eq!(
+            signer("new", &[], 0).verify("open", "request-1", &old_token),
+            Err(TokenError::InvalidSignature)
+        );
+    }
+}
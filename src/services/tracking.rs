@@ -0,0 +1,227 @@
+use crate::services::plaintext::decode_entities;
+use axum::http::HeaderMap;
+use regex::{Captures, Regex};
+use std::net::IpAddr;
+
+lazy_static::lazy_static! {
+    static ref ANCHOR_TAG_REGEX: Regex = Regex::new(r"(?is)<a\s[^>]*>").unwrap();
+    static ref HREF_ATTR_REGEX: Regex = Regex::new(
+        r#"(?i)(\shref\s*=\s*)(?:"([^"]*)"|'([^']*)'|([^\s>"']+))"#
+    ).unwrap();
+}
+
+/// 클릭 추적 대상 링크 (http/https만, 본문에 나타난 순서대로)
+///
+/// 인덱스는 클릭 URL에 포함되며, 리다이렉트 시 이 목록에서 원래 주소를 다시 찾습니다.
+pub fn tracked_links(html: &str) -> Vec<String> {
+    let mut links = Vec::new();
+    for tag in ANCHOR_TAG_REGEX.find_iter(html) {
+        if let Some(url) = HREF_ATTR_REGEX
+            .captures(tag.as_str())
+            .and_then(|caps| trackable_url(&caps))
+        {
+            links.push(url);
+        }
+    }
+    links
+}
+
+/// 추적 대상 링크의 href를 `click_url(인덱스, 원래 주소)`로 교체
+pub fn rewrite_links(html: &str, click_url: impl Fn(usize, &str) -> String) -> String {
+    let mut index = 0;
+    ANCHOR_TAG_REGEX
+        .replace_all(html, |tag: &Captures| {
+            let tag = &tag[0];
+            let Some(caps) = HREF_ATTR_REGEX.captures(tag) else {
+                return tag.to_string();
+            };
+            let Some(url) = trackable_url(&caps) else {
+                return tag.to_string();
+            };
+
+            let href = caps.get(0).unwrap();
+            let rewritten = format!(
+                "{}{}\"{}\"{}",
+                &tag[..href.start()],
+                &caps[1],
+                click_url(index, &url).replace('&', "&amp;"),
+                &tag[href.end()..]
+            );
+            index += 1;
+            rewritten
+        })
+        .into_owned()
+}
+
+fn trackable_url(caps: &Captures) -> Option<String> {
+    let raw = caps.get(2).or(caps.get(3)).or(caps.get(4))?.as_str();
+    let url = decode_entities(raw).trim().to_string();
+
+    let lower = url.to_ascii_lowercase();
+    (lower.starts_with("http://") || lower.starts_with("https://")).then_some(url)
+}
+
+/// 사람이 아닌 주체(이미지 프록시, 프라이버시 보호용 사전 로드)에 의한 오픈
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MachineOpen {
+    /// Apple Mail Privacy Protection (수신 시점에 이미지를 미리 로드)
+    AppleMailPrivacy,
+    GmailImageProxy,
+    YahooImageProxy,
+    MissingUserAgent,
+}
+
+impl MachineOpen {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            MachineOpen::AppleMailPrivacy => "apple_mail_privacy",
+            MachineOpen::GmailImageProxy => "gmail_image_proxy",
+            MachineOpen::YahooImageProxy => "yahoo_image_proxy",
+            MachineOpen::MissingUserAgent => "missing_user_agent",
+        }
+    }
+}
+
+/// User-Agent와 클라이언트 IP로 기계 오픈 여부 판별 (사람의 오픈이면 None)
+pub fn classify_open(user_agent: Option<&str>, ip: Option<IpAddr>) -> Option<MachineOpen> {
+    let user_agent = user_agent.map(str::trim).unwrap_or("");
+    if user_agent.is_empty() {
+        return Some(MachineOpen::MissingUserAgent);
+    }
+    if user_agent.contains("GoogleImageProxy") {
+        return Some(MachineOpen::GmailImageProxy);
+    }
+    if user_agent.contains("YahooMailProxy") {
+        return Some(MachineOpen::YahooImageProxy);
+    }
+
+    // Apple 프록시는 버전 정보 없는 "Mozilla/5.0"을 사용하거나 Apple 소유 대역(17.0.0.0/8)에서 요청
+    let apple_network = matches!(ip, Some(IpAddr::V4(ip)) if ip.octets()[0] == 17);
+    if user_agent == "Mozilla/5.0" || apple_network {
+        return Some(MachineOpen::AppleMailPrivacy);
+    }
+
+    None
+}
+
+/// 신뢰하는 프록시 헤더를 설정 순서대로 확인하고, 없으면 연결 주소 사용
+///
+/// `X-Forwarded-For`처럼 여러 주소가 나열된 경우 가장 왼쪽(원래 클라이언트) 주소를 사용합니다.
+pub fn client_ip(
+    headers: &HeaderMap,
+    trusted_headers: &[String],
+    peer: Option<IpAddr>,
+) -> Option<IpAddr> {
+    trusted_headers
+        .iter()
+        .filter_map(|name| headers.get(name.as_str())?.to_str().ok())
+        .find_map(|value| value.split(',').next()?.trim().parse().ok())
+        .or(peer)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const HTML: &str = r##"<p><a href="https://example.com/a?x=1&amp;y=2">A</a>
+        <a class="btn" href='http://example.com/b' target="_blank">B</a>
+        <a href="mailto:help@example.com">Mail</a> <a href="#top">Top</a>
+        <A HREF=https://example.com/c>C</A></p>"##;
+
+    #[test]
+    fn test_tracked_links() {
+        // http/https 링크만 순서대로 추출하고 HTML 엔티티는 디코딩
+        assert_eq!(
+            tracked_links(HTML),
+            vec![
+                "https://example.com/a?x=1&y=2".to_string(),
+                "http://example.com/b".to_string(),
+                "https://example.com/c".to_string(),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_rewrite_links() {
+        let rewritten = rewrite_links(HTML, |index, _| {
+            format!("https://t.example.com/click?requestId=r&link={}", index)
+        });
+
+        assert!(rewritten
+            .contains(r#"<a href="https://t.example.com/click?requestId=r&amp;link=0">A</a>"#));
+        // 다른 속성은 그대로 유지
+        assert!(rewritten.contains(
+            r#"<a class="btn" href="https://t.example.com/click?requestId=r&amp;link=1" target="_blank">B</a>"#
+        ));
+        assert!(rewritten
+            .contains(r#"<A HREF="https://t.example.com/click?requestId=r&amp;link=2">C</A>"#));
+        // 추적 대상이 아닌 링크는 변경하지 않음
+        assert!(rewritten.contains(r#"<a href="mailto:help@example.com">Mail</a>"#));
+        assert!(rewritten.contains(r##"<a href="#top">Top</a>"##));
+    }
+
+    #[test]
+    fn test_data_href_is_not_a_link() {
+        // data-href 같은 다른 속성이 아닌 실제 href만 추적
+        let html = r#"<a data-href="https://a.example.com" href="https://b.example.com">B</a>"#;
+        assert_eq!(
+            tracked_links(html),
+            vec!["https://b.example.com".to_string()]
+        );
+        assert_eq!(
+            rewrite_links(html, |index, _| format!("https://t.example.com/{}", index)),
+            r#"<a data-href="https://a.example.com" href="https://t.example.com/0">B</a>"#
+        );
+    }
+
+    #[test]
+    fn test_classify_open() {
+        let apple_ip: IpAddr = "17.58.1.2".parse().unwrap();
+        let other_ip: IpAddr = "203.0.113.5".parse().unwrap();
+        let browser =
+            "Mozilla/5.0 (Macintosh; Intel Mac OS X 10_15_7) AppleWebKit/605.1.15 (KHTML, like Gecko)";
+
+        assert_eq!(classify_open(Some(browser), Some(other_ip)), None);
+        assert_eq!(
+            classify_open(Some("Mozilla/5.0"), Some(other_ip)),
+            Some(MachineOpen::AppleMailPrivacy)
+        );
+        assert_eq!(
+            classify_open(Some(browser), Some(apple_ip)),
+            Some(MachineOpen::AppleMailPrivacy)
+        );
+        assert_eq!(
+            classify_open(
+                Some("Mozilla/5.0 (Windows NT 5.1; rv:11.0) Gecko Firefox/11.0 (via ggpht.com GoogleImageProxy)"),
+                None
+            ),
+            Some(MachineOpen::GmailImageProxy)
+        );
+        assert_eq!(
+            classify_open(None, Some(other_ip)),
+            Some(MachineOpen::MissingUserAgent)
+        );
+    }
+
+    #[test]
+    fn test_client_ip() {
+        let peer: IpAddr = "10.0.0.1".parse().unwrap();
+        let mut headers = HeaderMap::new();
+        headers.insert("x-forwarded-for", "203.0.113.5, 10.0.0.2".parse().unwrap());
+        headers.insert("x-real-ip", "198.51.100.7".parse().unwrap());
+
+        // 신뢰하지 않는 헤더는 무시
+        assert_eq!(client_ip(&headers, &[], Some(peer)), Some(peer));
+        // 설정 순서대로 첫 번째로 유효한 헤더 사용
+        let trusted = vec!["x-real-ip".to_string(), "x-forwarded-for".to_string()];
+        assert_eq!(
+            client_ip(&headers, &trusted, Some(peer)),
+            Some("198.51.100.7".parse().unwrap())
+        );
+        let trusted = vec!["x-forwarded-for".to_string()];
+        assert_eq!(
+            client_ip(&headers, &trusted, Some(peer)),
+            Some("203.0.113.5".parse().unwrap())
+        );
+    }
+}